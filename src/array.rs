@@ -0,0 +1,129 @@
+use typenum::{consts::*, Unsigned};
+
+// fixed-size array
+
+/// A trait for fixed-size arrays, which length is mapped to a typenum
+/// [Unsigned] type.
+///
+/// It is implemented for arrays of length from 0 to 32.
+pub trait FixedArray {
+    type Item;
+    type Length: Unsigned;
+
+    fn as_slice(&self) -> &[Self::Item];
+    fn into_vec(self) -> Vec<Self::Item>;
+}
+
+// array type of typenum length
+
+/// A type operator that maps a typenum [Unsigned] length to
+/// the array type of that length.
+///
+/// It is implemented for lengths from 0 to 32.
+pub trait ArrayOf<Item>
+where
+    Self: Unsigned,
+    Self::Output: FixedArray<Item = Item, Length = Self>,
+{
+    type Output;
+
+    /// Converts a [Vec] to the array. It panics if the length of vector
+    /// does not agree.
+    fn from_vec(vec: Vec<Item>) -> Self::Output;
+}
+
+pub type ArrayOfOutput<Length, Item> = <Length as ArrayOf<Item>>::Output;
+
+macro_rules! impl_fixed_array {
+    ($($len:expr => $size:ty [$($item:ident)*]),*) => {
+        $(
+            impl<T> FixedArray for [T; $len] {
+                type Item = T;
+                type Length = $size;
+
+                fn as_slice(&self) -> &[T] {
+                    self
+                }
+
+                fn into_vec(self) -> Vec<T> {
+                    (Box::new(self) as Box<[T]>).into_vec()
+                }
+            }
+
+            impl<T> ArrayOf<T> for $size {
+                type Output = [T; $len];
+
+                #[allow(unused_mut, unused_variables)]
+                fn from_vec(vec: Vec<T>) -> Self::Output {
+                    assert_eq!(vec.len(), $len, "the vector length does not match");
+                    let mut iter = vec.into_iter();
+                    [$({
+                        let $item = iter.next().unwrap();
+                        $item
+                    }),*]
+                }
+            }
+        )*
+    };
+}
+
+impl_fixed_array! {
+    0 => U0 [],
+    1 => U1 [item0],
+    2 => U2 [item0 item1],
+    3 => U3 [item0 item1 item2],
+    4 => U4 [item0 item1 item2 item3],
+    5 => U5 [item0 item1 item2 item3 item4],
+    6 => U6 [item0 item1 item2 item3 item4 item5],
+    7 => U7 [item0 item1 item2 item3 item4 item5 item6],
+    8 => U8 [item0 item1 item2 item3 item4 item5 item6 item7],
+    9 => U9 [item0 item1 item2 item3 item4 item5 item6 item7 item8],
+    10 => U10 [item0 item1 item2 item3 item4 item5 item6 item7 item8 item9],
+    11 => U11 [item0 item1 item2 item3 item4 item5 item6 item7 item8 item9 item10],
+    12 => U12 [item0 item1 item2 item3 item4 item5 item6 item7 item8 item9 item10 item11],
+    13 => U13 [item0 item1 item2 item3 item4 item5 item6 item7 item8 item9 item10 item11 item12],
+    14 => U14 [item0 item1 item2 item3 item4 item5 item6 item7 item8 item9 item10 item11 item12 item13],
+    15 => U15 [item0 item1 item2 item3 item4 item5 item6 item7 item8 item9 item10 item11 item12 item13 item14],
+    16 => U16 [item0 item1 item2 item3 item4 item5 item6 item7 item8 item9 item10 item11 item12 item13 item14 item15],
+    17 => U17 [item0 item1 item2 item3 item4 item5 item6 item7 item8 item9 item10 item11 item12 item13 item14 item15 item16],
+    18 => U18 [item0 item1 item2 item3 item4 item5 item6 item7 item8 item9 item10 item11 item12 item13 item14 item15 item16 item17],
+    19 => U19 [item0 item1 item2 item3 item4 item5 item6 item7 item8 item9 item10 item11 item12 item13 item14 item15 item16 item17 item18],
+    20 => U20 [item0 item1 item2 item3 item4 item5 item6 item7 item8 item9 item10 item11 item12 item13 item14 item15 item16 item17 item18 item19],
+    21 => U21 [item0 item1 item2 item3 item4 item5 item6 item7 item8 item9 item10 item11 item12 item13 item14 item15 item16 item17 item18 item19 item20],
+    22 => U22 [item0 item1 item2 item3 item4 item5 item6 item7 item8 item9 item10 item11 item12 item13 item14 item15 item16 item17 item18 item19 item20 item21],
+    23 => U23 [item0 item1 item2 item3 item4 item5 item6 item7 item8 item9 item10 item11 item12 item13 item14 item15 item16 item17 item18 item19 item20 item21 item22],
+    24 => U24 [item0 item1 item2 item3 item4 item5 item6 item7 item8 item9 item10 item11 item12 item13 item14 item15 item16 item17 item18 item19 item20 item21 item22 item23],
+    25 => U25 [item0 item1 item2 item3 item4 item5 item6 item7 item8 item9 item10 item11 item12 item13 item14 item15 item16 item17 item18 item19 item20 item21 item22 item23 item24],
+    26 => U26 [item0 item1 item2 item3 item4 item5 item6 item7 item8 item9 item10 item11 item12 item13 item14 item15 item16 item17 item18 item19 item20 item21 item22 item23 item24 item25],
+    27 => U27 [item0 item1 item2 item3 item4 item5 item6 item7 item8 item9 item10 item11 item12 item13 item14 item15 item16 item17 item18 item19 item20 item21 item22 item23 item24 item25 item26],
+    28 => U28 [item0 item1 item2 item3 item4 item5 item6 item7 item8 item9 item10 item11 item12 item13 item14 item15 item16 item17 item18 item19 item20 item21 item22 item23 item24 item25 item26 item27],
+    29 => U29 [item0 item1 item2 item3 item4 item5 item6 item7 item8 item9 item10 item11 item12 item13 item14 item15 item16 item17 item18 item19 item20 item21 item22 item23 item24 item25 item26 item27 item28],
+    30 => U30 [item0 item1 item2 item3 item4 item5 item6 item7 item8 item9 item10 item11 item12 item13 item14 item15 item16 item17 item18 item19 item20 item21 item22 item23 item24 item25 item26 item27 item28 item29],
+    31 => U31 [item0 item1 item2 item3 item4 item5 item6 item7 item8 item9 item10 item11 item12 item13 item14 item15 item16 item17 item18 item19 item20 item21 item22 item23 item24 item25 item26 item27 item28 item29 item30],
+    32 => U32 [item0 item1 item2 item3 item4 item5 item6 item7 item8 item9 item10 item11 item12 item13 item14 item15 item16 item17 item18 item19 item20 item21 item22 item23 item24 item25 item26 item27 item28 item29 item30 item31]
+}
+
+// tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use type_freak::control::IfSameOutput;
+
+    type AssertSame<Lhs, Rhs> = IfSameOutput<(), Lhs, Rhs>;
+
+    type Assert1 = AssertSame<<[u8; 0] as FixedArray>::Length, U0>;
+    type Assert2 = AssertSame<<[[f32; 3]; 7] as FixedArray>::Length, U7>;
+    type Assert3 = AssertSame<ArrayOfOutput<U4, i64>, [i64; 4]>;
+
+    #[test]
+    fn fixed_array_test() {
+        let _: Assert1 = ();
+        let _: Assert2 = ();
+        let _: Assert3 = ();
+
+        assert_eq!([1, 2, 3].as_slice(), &[1, 2, 3]);
+        assert_eq!([[1, 2], [3, 4]].into_vec(), vec![[1, 2], [3, 4]]);
+        assert_eq!(<U3 as ArrayOf<_>>::from_vec(vec![5, 6, 7]), [5, 6, 7]);
+    }
+}
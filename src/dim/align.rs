@@ -0,0 +1,182 @@
+use super::{
+    DBroadcastBothOutput, DCons, DNil, DRemoveAt, DRemoveAtOutput, DSizeAt, DSizeAtOutput, Dim,
+    DimList, DimSize,
+};
+use std::marker::PhantomData;
+use type_freak::counter::Counter;
+use typenum::U1;
+
+/// The trait distinguishes whether a dimension of the target list is present
+/// in the aligned list or not.
+///
+/// Type positions of this trait can be inferred automatically. It is not intended
+/// to be manually specified by user.
+pub trait AlignMatcher {}
+
+/// Indicates the dimension is found at `Index`.
+pub struct AlignPresent<Index, Matcher>
+where
+    Index: Counter,
+    Matcher: AlignMatcher,
+{
+    _phantom: PhantomData<(Index, Matcher)>,
+}
+
+impl<Index, Matcher> AlignMatcher for AlignPresent<Index, Matcher>
+where
+    Index: Counter,
+    Matcher: AlignMatcher,
+{
+}
+
+/// Indicates the dimension is missing, thus a size-one dimension is inserted.
+pub struct AlignAbsent<Matcher>
+where
+    Matcher: AlignMatcher,
+{
+    _phantom: PhantomData<Matcher>,
+}
+
+impl<Matcher> AlignMatcher for AlignAbsent<Matcher> where Matcher: AlignMatcher {}
+
+/// Indicates the end of target list.
+pub struct AlignEnd;
+
+impl AlignMatcher for AlignEnd {}
+
+// align to target by names
+
+/// A type operator that reorders the dimensions by names following `Target`,
+/// and inserts size-one dimensions for names missing in the input.
+///
+/// Every dimension of the input must appear in `Target`.
+pub trait DAlignTo<Target, Matcher>
+where
+    Target: DimList,
+    Matcher: AlignMatcher,
+    Self: DimList,
+    Self::Output: DimList,
+{
+    type Output;
+
+    /// Pops the source positions from `remaining` in the order of output
+    /// dimensions, where inserted dimensions are marked as `None`.
+    fn append_align_index(remaining: &mut Vec<usize>, indexes: &mut Vec<Option<usize>>);
+
+    /// For each output dimension, the position in the input, or `None`
+    /// if it is inserted.
+    fn align_index() -> Vec<Option<usize>> {
+        let mut remaining = (0..Self::shape_i64().len()).collect::<Vec<_>>();
+        let mut indexes = vec![];
+        Self::append_align_index(&mut remaining, &mut indexes);
+        indexes
+    }
+}
+
+pub type DAlignToOutput<List, Target, Matcher> = <List as DAlignTo<Target, Matcher>>::Output;
+
+impl DAlignTo<DNil, AlignEnd> for DNil {
+    type Output = DNil;
+
+    fn append_align_index(_remaining: &mut Vec<usize>, _indexes: &mut Vec<Option<usize>>) {}
+}
+
+impl<Name, Size, TargetTail, Index, Matcher, List>
+    DAlignTo<DCons<Name, Size, TargetTail>, AlignPresent<Index, Matcher>> for List
+where
+    Name: Dim,
+    Size: DimSize,
+    TargetTail: DimList,
+    Index: Counter,
+    Matcher: AlignMatcher,
+    List: DimList + DSizeAt<Name, Index> + DRemoveAt<Name, Index>,
+    DRemoveAtOutput<List, Name, Index>: DAlignTo<TargetTail, Matcher>,
+{
+    type Output = DCons<
+        Name,
+        DSizeAtOutput<List, Name, Index>,
+        DAlignToOutput<DRemoveAtOutput<List, Name, Index>, TargetTail, Matcher>,
+    >;
+
+    fn append_align_index(remaining: &mut Vec<usize>, indexes: &mut Vec<Option<usize>>) {
+        let index = <List as DRemoveAt<Name, Index>>::index();
+        indexes.push(Some(remaining.remove(index)));
+        <DRemoveAtOutput<List, Name, Index> as DAlignTo<TargetTail, Matcher>>::append_align_index(
+            remaining, indexes,
+        );
+    }
+}
+
+impl<Name, Size, TargetTail, Matcher, List>
+    DAlignTo<DCons<Name, Size, TargetTail>, AlignAbsent<Matcher>> for List
+where
+    Name: Dim,
+    Size: DimSize,
+    TargetTail: DimList,
+    Matcher: AlignMatcher,
+    List: DimList + DAlignTo<TargetTail, Matcher>,
+{
+    type Output = DCons<Name, U1, DAlignToOutput<List, TargetTail, Matcher>>;
+
+    fn append_align_index(remaining: &mut Vec<usize>, indexes: &mut Vec<Option<usize>>) {
+        indexes.push(None);
+        <List as DAlignTo<TargetTail, Matcher>>::append_align_index(remaining, indexes);
+    }
+}
+
+// align and broadcast
+
+/// The output of broadcasting `LDims` with `RDims` aligned to it by names.
+pub type DAlignedOutput<LDims, RDims, AlignM, BcastM> =
+    DBroadcastBothOutput<LDims, DAlignToOutput<RDims, LDims, AlignM>, BcastM>;
+
+// tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{make_dims, DimListType};
+    use type_freak::control::IfSameOutput;
+    use typenum::consts::*;
+
+    make_dims! {A, B, C, D}
+
+    type SomeDims = DimListType! {(A, U3), (B, U2), (C, U4), (D, U5)};
+
+    type AssertSame<Lhs, Rhs> = IfSameOutput<(), Lhs, Rhs>;
+
+    type Assert1<Matcher> = AssertSame<
+        DAlignToOutput<DimListType! {(C, U4), (A, U3)}, SomeDims, Matcher>,
+        DimListType! {(A, U3), (B, U1), (C, U4), (D, U1)},
+    >;
+
+    type Assert2<Matcher> = AssertSame<
+        DAlignToOutput<DimListType! {(D, U5), (B, U1), (A, U3), (C, U4)}, SomeDims, Matcher>,
+        DimListType! {(A, U3), (B, U1), (C, U4), (D, U5)},
+    >;
+
+    type Assert3<Matcher> = AssertSame<
+        DAlignToOutput<DimListType! {}, SomeDims, Matcher>,
+        DimListType! {(A, U1), (B, U1), (C, U1), (D, U1)},
+    >;
+
+    #[test]
+    fn dim_align_test() {
+        let _: Assert1<_> = ();
+        assert_eq!(
+            <DimListType! {(C, U4), (A, U3)} as DAlignTo<SomeDims, _>>::align_index(),
+            vec![Some(1), None, Some(0), None]
+        );
+
+        let _: Assert2<_> = ();
+        assert_eq!(
+            <DimListType! {(D, U5), (B, U1), (A, U3), (C, U4)} as DAlignTo<SomeDims, _>>::align_index(),
+            vec![Some(2), Some(1), Some(3), Some(0)]
+        );
+
+        let _: Assert3<_> = ();
+
+        // It triggers compile error because D is missing in the target
+        // let _ = <DimListType! {(D, U5)} as DAlignTo<DimListType! {(A, U3)}, _>>::align_index();
+    }
+}
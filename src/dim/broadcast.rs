@@ -1,6 +1,6 @@
-use super::{DCons, DNil, DReverse, DReverseOutput, Dim, DimList};
+use super::{DCons, DNil, DReverse, DReverseOutput, Dim, DimList, DimSize, Dyn};
 use std::marker::PhantomData;
-use typenum::{Unsigned, U1};
+use typenum::{Bit, UInt, Unsigned, U1};
 
 /// The trait distinguishes the cases of identical dimensions, one of both is one,
 /// or missing one of them.
@@ -39,6 +39,22 @@ where
 
 impl<Matcher> BroadcastMatcher for BcastIdentical<Matcher> where Matcher: BroadcastMatcher {}
 
+/// Indicates one of the dimensions is [Dyn] and the other one is static and
+/// larger than one. The static size is taken for the output.
+///
+/// The runtime size of the [Dyn] side is not checked by the infallible
+/// operations. Use the `try_` variants, for example
+/// [try_broadcast_add](crate::tensor::TensorBinaryOp::try_broadcast_add),
+/// to check it against the static size.
+pub struct BcastDyn<Matcher>
+where
+    Matcher: BroadcastMatcher,
+{
+    _phantom: PhantomData<Matcher>,
+}
+
+impl<Matcher> BroadcastMatcher for BcastDyn<Matcher> where Matcher: BroadcastMatcher {}
+
 /// Indicates at least one of the dimensions is missing.
 pub struct BcastAbscent;
 
@@ -67,7 +83,7 @@ impl DBroadcastingTo<DNil, BcastAbscent> for DNil {
 impl<Name, Size, Tail> DBroadcastingTo<DCons<Name, Size, Tail>, BcastAbscent> for DNil
 where
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: DimList,
     DNil: DBroadcastingTo<Tail, BcastAbscent>,
 {
@@ -79,7 +95,7 @@ impl<Matcher, Name, Size, LTail, RTail>
 where
     Matcher: BroadcastMatcher,
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     LTail: DimList + DBroadcastingTo<RTail, Matcher>,
     RTail: DimList,
     DBroadcastingToOutput<LTail, RTail, Matcher>: DimList,
@@ -92,7 +108,7 @@ impl<Matcher, Name, Size, LTail, RTail>
 where
     Matcher: BroadcastMatcher,
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     LTail: DimList + DBroadcastingTo<RTail, Matcher>,
     RTail: DimList,
     DBroadcastingToOutput<LTail, RTail, Matcher>: DimList,
@@ -105,7 +121,7 @@ impl<Matcher, Name, Size, LTail, RTail> DBroadcastingTo<DCons<Name, U1, RTail>,
 where
     Matcher: BroadcastMatcher,
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     LTail: DimList + DBroadcastingTo<RTail, Matcher>,
     RTail: DimList,
     DBroadcastingToOutput<LTail, RTail, Matcher>: DimList,
@@ -113,6 +129,46 @@ where
     type Output = DCons<Name, Size, DBroadcastingToOutput<LTail, RTail, Matcher>>;
 }
 
+impl<Matcher, Name, Higher, HigherBit, LowerBit, LTail, RTail>
+    DBroadcastingTo<DCons<Name, UInt<UInt<Higher, HigherBit>, LowerBit>, RTail>, BcastDyn<Matcher>>
+    for DCons<Name, Dyn, LTail>
+where
+    Matcher: BroadcastMatcher,
+    Name: Dim,
+    Higher: Unsigned,
+    HigherBit: Bit,
+    LowerBit: Bit,
+    LTail: DimList + DBroadcastingTo<RTail, Matcher>,
+    RTail: DimList,
+    DBroadcastingToOutput<LTail, RTail, Matcher>: DimList,
+{
+    type Output = DCons<
+        Name,
+        UInt<UInt<Higher, HigherBit>, LowerBit>,
+        DBroadcastingToOutput<LTail, RTail, Matcher>,
+    >;
+}
+
+impl<Matcher, Name, Higher, HigherBit, LowerBit, LTail, RTail>
+    DBroadcastingTo<DCons<Name, Dyn, RTail>, BcastDyn<Matcher>>
+    for DCons<Name, UInt<UInt<Higher, HigherBit>, LowerBit>, LTail>
+where
+    Matcher: BroadcastMatcher,
+    Name: Dim,
+    Higher: Unsigned,
+    HigherBit: Bit,
+    LowerBit: Bit,
+    LTail: DimList + DBroadcastingTo<RTail, Matcher>,
+    RTail: DimList,
+    DBroadcastingToOutput<LTail, RTail, Matcher>: DimList,
+{
+    type Output = DCons<
+        Name,
+        UInt<UInt<Higher, HigherBit>, LowerBit>,
+        DBroadcastingToOutput<LTail, RTail, Matcher>,
+    >;
+}
+
 // broadcast to size of target from tail
 
 /// Broadcasts the input [DimList] to the size of target [DimList] from
@@ -190,7 +246,7 @@ impl DBroadcastingBoth<DNil, BcastAbscent> for DNil {
 impl<Name, Size, Tail> DBroadcastingBoth<DCons<Name, Size, Tail>, BcastAbscent> for DNil
 where
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: DimList,
     DNil: DBroadcastingBoth<Tail, BcastAbscent>,
 {
@@ -200,7 +256,7 @@ where
 impl<Name, Size, Tail> DBroadcastingBoth<DNil, BcastAbscent> for DCons<Name, Size, Tail>
 where
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: DimList + DBroadcastingBoth<DNil, BcastAbscent>,
 {
     type Output = DCons<Name, Size, DBroadcastingBothOutput<Tail, DNil, BcastAbscent>>;
@@ -212,7 +268,7 @@ impl<Matcher, Name, Size, LTail, RTail>
 where
     Matcher: BroadcastMatcher,
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     LTail: DimList + DBroadcastingBoth<RTail, Matcher>,
     RTail: DimList,
     DBroadcastingBothOutput<LTail, RTail, Matcher>: DimList,
@@ -225,7 +281,7 @@ impl<Matcher, Name, Size, LTail, RTail>
 where
     Matcher: BroadcastMatcher,
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     LTail: DimList + DBroadcastingBoth<RTail, Matcher>,
     RTail: DimList,
     DBroadcastingBothOutput<LTail, RTail, Matcher>: DimList,
@@ -238,7 +294,7 @@ impl<Matcher, Name, Size, LTail, RTail>
 where
     Matcher: BroadcastMatcher,
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     LTail: DimList + DBroadcastingBoth<RTail, Matcher>,
     RTail: DimList,
     DBroadcastingBothOutput<LTail, RTail, Matcher>: DimList,
@@ -246,6 +302,48 @@ where
     type Output = DCons<Name, Size, DBroadcastingBothOutput<LTail, RTail, Matcher>>;
 }
 
+impl<Matcher, Name, Higher, HigherBit, LowerBit, LTail, RTail>
+    DBroadcastingBoth<
+        DCons<Name, UInt<UInt<Higher, HigherBit>, LowerBit>, RTail>,
+        BcastDyn<Matcher>,
+    > for DCons<Name, Dyn, LTail>
+where
+    Matcher: BroadcastMatcher,
+    Name: Dim,
+    Higher: Unsigned,
+    HigherBit: Bit,
+    LowerBit: Bit,
+    LTail: DimList + DBroadcastingBoth<RTail, Matcher>,
+    RTail: DimList,
+    DBroadcastingBothOutput<LTail, RTail, Matcher>: DimList,
+{
+    type Output = DCons<
+        Name,
+        UInt<UInt<Higher, HigherBit>, LowerBit>,
+        DBroadcastingBothOutput<LTail, RTail, Matcher>,
+    >;
+}
+
+impl<Matcher, Name, Higher, HigherBit, LowerBit, LTail, RTail>
+    DBroadcastingBoth<DCons<Name, Dyn, RTail>, BcastDyn<Matcher>>
+    for DCons<Name, UInt<UInt<Higher, HigherBit>, LowerBit>, LTail>
+where
+    Matcher: BroadcastMatcher,
+    Name: Dim,
+    Higher: Unsigned,
+    HigherBit: Bit,
+    LowerBit: Bit,
+    LTail: DimList + DBroadcastingBoth<RTail, Matcher>,
+    RTail: DimList,
+    DBroadcastingBothOutput<LTail, RTail, Matcher>: DimList,
+{
+    type Output = DCons<
+        Name,
+        UInt<UInt<Higher, HigherBit>, LowerBit>,
+        DBroadcastingBothOutput<LTail, RTail, Matcher>,
+    >;
+}
+
 // broadcast both sizes from tail
 
 /// Broadcasts the input [DimList] to the size of target [DimList] from
@@ -368,6 +466,23 @@ mod tests {
         DimListType! {(A, U3), (B, U2), (C, U4), (D, U1), (E, U9)},
     >;
 
+    type DynDims = DimListType! {(A, Dyn), (B, U2), (C, Dyn)};
+
+    type Assert11<Matcher> = AssertSame<
+        DBroadcastBothOutput<DynDims, YDims, Matcher>,
+        DimListType! {(A, U3), (B, U2), (C, U4)},
+    >;
+
+    type Assert12<Matcher> = AssertSame<
+        DBroadcastBothOutput<XDims, DynDims, Matcher>,
+        DimListType! {(A, U3), (B, U2), (C, Dyn)},
+    >;
+
+    type Assert13<Matcher> = AssertSame<
+        DBroadcastToOutput<DynDims, DynDims, Matcher>,
+        DimListType! {(A, Dyn), (B, U2), (C, Dyn)},
+    >;
+
     #[test]
     fn dim_broadcast_test() {
         let _: Assert1<_> = ();
@@ -380,5 +495,8 @@ mod tests {
         let _: Assert8<_> = ();
         let _: Assert9<_> = ();
         let _: Assert10<_> = ();
+        let _: Assert11<_> = ();
+        let _: Assert12<_> = ();
+        let _: Assert13<_> = ();
     }
 }
@@ -0,0 +1,343 @@
+use super::{
+    DExtend, DExtendOutput, DExtractDim, DExtractDimOutput, DIndexOfMany, DMarkMany,
+    DMarkManyOutput, DRemoveMarked, DRemoveMarkedOutput, DSelectMany, DSelectManyOutput, DSizeAt,
+    DSizeAtOutput, Dim, DimList,
+};
+use type_freak::{
+    counter::Counter,
+    list::{LCons, LNil, TList},
+};
+
+// sizes of many dimensions
+
+/// A type operator that collects the sizes of `Targets` into a [TList].
+pub trait DSizeAtMany<Targets, Indexes>
+where
+    Targets: TList,
+    Indexes: TList,
+    Self: DimList,
+    Self::Output: TList,
+{
+    type Output;
+}
+
+pub type DSizeAtManyOutput<List, Targets, Indexes> =
+    <List as DSizeAtMany<Targets, Indexes>>::Output;
+
+impl<List> DSizeAtMany<LNil, LNil> for List
+where
+    List: DimList,
+{
+    type Output = LNil;
+}
+
+impl<Target, TRemain, Index, IRemain, List>
+    DSizeAtMany<LCons<Target, TRemain>, LCons<Index, IRemain>> for List
+where
+    Target: Dim,
+    TRemain: TList,
+    Index: Counter,
+    IRemain: TList,
+    List: DimList + DSizeAt<Target, Index> + DSizeAtMany<TRemain, IRemain>,
+{
+    type Output =
+        LCons<DSizeAtOutput<List, Target, Index>, DSizeAtManyOutput<List, TRemain, IRemain>>;
+}
+
+// remove contracted and batch dimensions
+
+/// A type operator that removes both `Targets` and `Batches` dimensions.
+/// A name cannot be in both of them.
+pub trait DContractRest<Batches, Targets, BatchIndexes, TargetIndexes>
+where
+    Batches: TList,
+    Targets: TList,
+    BatchIndexes: TList,
+    TargetIndexes: TList,
+    Self: DimList,
+    Self::Output: DimList,
+{
+    type Output;
+
+    /// The positions of `Batches` in the order of `Batches`.
+    fn batch_indexes() -> Vec<usize>;
+
+    /// The positions of `Targets` in the order of `Targets`.
+    fn target_indexes() -> Vec<usize>;
+}
+
+pub type DContractRestOutput<List, Batches, Targets, BatchIndexes, TargetIndexes> =
+    <List as DContractRest<Batches, Targets, BatchIndexes, TargetIndexes>>::Output;
+
+impl<List, Batches, Targets, BatchIndexes, TargetIndexes>
+    DContractRest<Batches, Targets, BatchIndexes, TargetIndexes> for List
+where
+    Batches: TList,
+    Targets: TList,
+    BatchIndexes: TList,
+    TargetIndexes: TList,
+    List: DimList + DMarkMany<Targets, TargetIndexes>,
+    DMarkManyOutput<List, Targets, TargetIndexes>: DMarkMany<Batches, BatchIndexes>,
+    DMarkManyOutput<DMarkManyOutput<List, Targets, TargetIndexes>, Batches, BatchIndexes>:
+        DRemoveMarked,
+{
+    type Output = DRemoveMarkedOutput<
+        DMarkManyOutput<DMarkManyOutput<List, Targets, TargetIndexes>, Batches, BatchIndexes>,
+    >;
+
+    fn batch_indexes() -> Vec<usize> {
+        <DMarkManyOutput<List, Targets, TargetIndexes> as DMarkMany<Batches, BatchIndexes>>::indexes(
+        )
+    }
+
+    fn target_indexes() -> Vec<usize> {
+        <List as DMarkMany<Targets, TargetIndexes>>::indexes()
+    }
+}
+
+// contraction
+
+/// A type operator that contracts `Targets` dimensions shared by both lists,
+/// while `Batches` dimensions shared by both lists are kept once.
+///
+/// The output consists of the `Batches` dimensions, followed by the remaining
+/// dimensions of left-hand-side and then those of right-hand-side. The
+/// `Targets` and `Batches` dimensions must have the same sizes on both sides,
+/// and the output names must be distinct. Since the absence of a name
+/// cannot be checked in compile time, shared names are not detected
+/// automatically and must be listed in `Batches`.
+///
+/// The `Indexes` is a 5-tuple of [TList]s of counters, which locate `Batches`
+/// and `Targets` in the left-hand-side, then in the right-hand-side, and the
+/// output names in the output list respectively.
+pub trait DContract<Rhs, Batches, Targets, Indexes>
+where
+    Rhs: DimList,
+    Batches: TList,
+    Targets: TList,
+    Self: DimList,
+    Self::Output: DimList,
+{
+    type Output;
+
+    /// The einsum equation of the contraction. It panics if there are more
+    /// than 52 distinct dimensions in both lists.
+    fn equation() -> String;
+}
+
+pub type DContractOutput<Lhs, Rhs, Batches, Targets, Indexes> =
+    <Lhs as DContract<Rhs, Batches, Targets, Indexes>>::Output;
+
+/// The output of contraction before checking the distinct names.
+pub type DContractingOutput<
+    Lhs,
+    Rhs,
+    Batches,
+    Targets,
+    LBIndexes,
+    LTIndexes,
+    RBIndexes,
+    RTIndexes,
+> = DExtendOutput<
+    DSelectManyOutput<Lhs, Batches, LBIndexes>,
+    DExtendOutput<
+        DContractRestOutput<Lhs, Batches, Targets, LBIndexes, LTIndexes>,
+        DContractRestOutput<Rhs, Batches, Targets, RBIndexes, RTIndexes>,
+    >,
+>;
+
+impl<Lhs, Rhs, Batches, Targets, LBIndexes, LTIndexes, RBIndexes, RTIndexes, OIndexes>
+    DContract<Rhs, Batches, Targets, (LBIndexes, LTIndexes, RBIndexes, RTIndexes, OIndexes)> for Lhs
+where
+    Batches: TList,
+    Targets: TList,
+    LBIndexes: TList,
+    LTIndexes: TList,
+    RBIndexes: TList,
+    RTIndexes: TList,
+    OIndexes: TList,
+    Lhs: DimList
+        + DContractRest<Batches, Targets, LBIndexes, LTIndexes>
+        + DSizeAtMany<Targets, LTIndexes>
+        + DSizeAtMany<Batches, LBIndexes>
+        + DSelectMany<Batches, LBIndexes>,
+    Rhs: DimList
+        + DContractRest<Batches, Targets, RBIndexes, RTIndexes>
+        + DSizeAtMany<Targets, RTIndexes, Output = DSizeAtManyOutput<Lhs, Targets, LTIndexes>>
+        + DSizeAtMany<Batches, RBIndexes, Output = DSizeAtManyOutput<Lhs, Batches, LBIndexes>>,
+    DContractRestOutput<Lhs, Batches, Targets, LBIndexes, LTIndexes>:
+        DExtend<DContractRestOutput<Rhs, Batches, Targets, RBIndexes, RTIndexes>>,
+    DSelectManyOutput<Lhs, Batches, LBIndexes>: DExtend<
+        DExtendOutput<
+            DContractRestOutput<Lhs, Batches, Targets, LBIndexes, LTIndexes>,
+            DContractRestOutput<Rhs, Batches, Targets, RBIndexes, RTIndexes>,
+        >,
+    >,
+    DContractingOutput<Lhs, Rhs, Batches, Targets, LBIndexes, LTIndexes, RBIndexes, RTIndexes>:
+        DExtractDim
+            + DIndexOfMany<
+                DExtractDimOutput<
+                    DContractingOutput<
+                        Lhs,
+                        Rhs,
+                        Batches,
+                        Targets,
+                        LBIndexes,
+                        LTIndexes,
+                        RBIndexes,
+                        RTIndexes,
+                    >,
+                >,
+                OIndexes,
+            >,
+{
+    type Output =
+        DContractingOutput<Lhs, Rhs, Batches, Targets, LBIndexes, LTIndexes, RBIndexes, RTIndexes>;
+
+    fn equation() -> String {
+        let lhs_batches =
+            <Lhs as DContractRest<Batches, Targets, LBIndexes, LTIndexes>>::batch_indexes();
+        let lhs_targets =
+            <Lhs as DContractRest<Batches, Targets, LBIndexes, LTIndexes>>::target_indexes();
+        let rhs_batches =
+            <Rhs as DContractRest<Batches, Targets, RBIndexes, RTIndexes>>::batch_indexes();
+        let rhs_targets =
+            <Rhs as DContractRest<Batches, Targets, RBIndexes, RTIndexes>>::target_indexes();
+        let lhs_len = Lhs::shape_i64().len();
+        let rhs_len = Rhs::shape_i64().len();
+
+        // the lhs dims take the first letters, and the rhs dims not shared
+        // with lhs take the following ones
+        let lhs_letters = (0..lhs_len).map(einsum_letter).collect::<Vec<_>>();
+        let mut next_letter = lhs_len;
+        let rhs_letters = (0..rhs_len)
+            .map(|index| {
+                let shared = rhs_targets
+                    .iter()
+                    .position(|&target| target == index)
+                    .map(|nth| lhs_targets[nth])
+                    .or_else(|| {
+                        rhs_batches
+                            .iter()
+                            .position(|&batch| batch == index)
+                            .map(|nth| lhs_batches[nth])
+                    });
+                match shared {
+                    Some(lhs_index) => einsum_letter(lhs_index),
+                    None => {
+                        next_letter += 1;
+                        einsum_letter(next_letter - 1)
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let output_letters = lhs_batches
+            .iter()
+            .map(|&index| lhs_letters[index])
+            .chain(
+                lhs_letters
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| {
+                        !lhs_targets.contains(index) && !lhs_batches.contains(index)
+                    })
+                    .map(|(_, &letter)| letter),
+            )
+            .chain(
+                rhs_letters
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| {
+                        !rhs_targets.contains(index) && !rhs_batches.contains(index)
+                    })
+                    .map(|(_, &letter)| letter),
+            )
+            .collect::<String>();
+
+        format!(
+            "{},{}->{}",
+            lhs_letters.into_iter().collect::<String>(),
+            rhs_letters.into_iter().collect::<String>(),
+            output_letters
+        )
+    }
+}
+
+/// The einsum subscript of `index`, which is one of `a-z` and then `A-Z`.
+/// It panics if there are more than 52 distinct dimensions, which is the
+/// limit of einsum.
+fn einsum_letter(index: usize) -> char {
+    match index {
+        0..=25 => (b'a' + index as u8) as char,
+        26..=51 => (b'A' + (index - 26) as u8) as char,
+        _ => panic!("einsum supports at most 52 distinct dimensions"),
+    }
+}
+
+// tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{make_dims, DimListType};
+    use type_freak::{control::IfSameOutput, TListType};
+    use typenum::consts::*;
+
+    make_dims! {Batch, Query, Key, Feature}
+
+    type QueryDims = DimListType! {(Query, U5), (Feature, U8)};
+    type KeyDims = DimListType! {(Batch, U2), (Feature, U8), (Key, U7)};
+
+    type AssertSame<Lhs, Rhs> = IfSameOutput<(), Lhs, Rhs>;
+
+    type Assert1<Idx> =
+        AssertSame<DSizeAtManyOutput<KeyDims, TListType! {Key, Batch}, Idx>, TListType! {U7, U2}>;
+
+    type Assert2<Idx> = AssertSame<
+        DContractOutput<QueryDims, KeyDims, TListType! {}, TListType! {Feature}, Idx>,
+        DimListType! {(Query, U5), (Batch, U2), (Key, U7)},
+    >;
+
+    type BatchedQueryDims = DimListType! {(Batch, U2), (Query, U5), (Feature, U8)};
+
+    type Assert3<Idx> = AssertSame<
+        DContractOutput<BatchedQueryDims, KeyDims, TListType! {Batch}, TListType! {Feature}, Idx>,
+        DimListType! {(Batch, U2), (Query, U5), (Key, U7)},
+    >;
+
+    #[test]
+    fn dim_contract_test() {
+        let _: Assert1<_> = ();
+
+        let _: Assert2<_> = ();
+        assert_eq!(
+            <QueryDims as DContract<KeyDims, TListType! {}, TListType! {Feature}, _>>::equation(),
+            "ab,cbd->acd"
+        );
+
+        // shared batch dims are kept once
+        let _: Assert3<_> = ();
+        assert_eq!(
+            <BatchedQueryDims as DContract<
+                KeyDims,
+                TListType! {Batch},
+                TListType! {Feature},
+                _,
+            >>::equation(),
+            "abc,acd->abd"
+        );
+
+        // letters continue to upper case
+        assert_eq!(einsum_letter(0), 'a');
+        assert_eq!(einsum_letter(25), 'z');
+        assert_eq!(einsum_letter(26), 'A');
+        assert_eq!(einsum_letter(51), 'Z');
+
+        // It triggers compile error because the sizes of Feature differ
+        // let _ = <QueryDims as DContract<DimListType! {(Feature, U9)}, TListType! {}, TListType! {Feature}, _>>::equation();
+
+        // It triggers compile error because Query appears on both sides but is not a batch dim
+        // let _ = <QueryDims as DContract<QueryDims, TListType! {}, TListType! {}, _>>::equation();
+    }
+}
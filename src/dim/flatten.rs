@@ -1,4 +1,4 @@
-use super::{DCons, DNil, Dim, DimList, NonScalarDim};
+use super::{DCons, DExtend, DExtendOutput, DNil, Dim, DimList, DimSize, NonScalarDim};
 use std::ops::Mul;
 use type_freak::counter::{Count, CountOutput, Counter, Current, Next};
 use typenum::{Prod, Unsigned, U1};
@@ -38,7 +38,7 @@ where
     Begin: Dim,
     End: Dim,
     EndIndex: Counter + Count,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: NonScalarDim,
     Self: DFlattening<NewName, U1, End, EndIndex>,
 {
@@ -56,7 +56,7 @@ where
     BeginIndex: Counter + Count,
     EndIndex: Counter + Count,
     NonBegin: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: NonScalarDim + DFlatten<NewName, Begin, End, BeginIndex, EndIndex>,
     Next<BeginIndex>: Count,
     Next<EndIndex>: Count,
@@ -153,7 +153,7 @@ where
     Begin: Dim,
     BeginIndex: Counter + Count,
     NonBegin: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: DimList + DFlattenFrom<NewName, Begin, BeginIndex>,
     Next<BeginIndex>: Count,
 {
@@ -278,6 +278,84 @@ where
     type Output = DFlatteningUntilOutput<Tail, NewName, Prod<ProdSize, Size>, End, EndIndex>;
 }
 
+// product of sizes
+
+/// A type operator that computes the product of all dimension sizes,
+/// that is, the number of elements.
+pub trait DSizeProduct
+where
+    Self: DimList,
+    Self::Output: Unsigned,
+{
+    type Output;
+}
+
+pub type DSizeProductOutput<List> = <List as DSizeProduct>::Output;
+
+impl DSizeProduct for DNil {
+    type Output = U1;
+}
+
+impl<Name, Size, Tail> DSizeProduct for DCons<Name, Size, Tail>
+where
+    Name: Dim,
+    Size: Unsigned + Mul<DSizeProductOutput<Tail>>,
+    Tail: DimList + DSizeProduct,
+    Prod<Size, DSizeProductOutput<Tail>>: Unsigned,
+{
+    type Output = Prod<Size, DSizeProductOutput<Tail>>;
+}
+
+// unflatten one dim to several dims
+
+/// A type operator that replaces the `Target` dimension with `NewDims`.
+/// The product of sizes of `NewDims` must be equal to the size of `Target`.
+pub trait DUnflatten<Target, NewDims, Index>
+where
+    Target: Dim,
+    NewDims: NonScalarDim,
+    Index: Counter,
+    Self: NonScalarDim,
+    Self::Output: NonScalarDim,
+    Self::Index: Unsigned,
+{
+    type Output;
+    type Index;
+}
+
+pub type DUnflattenOutput<List, Target, NewDims, Index> =
+    <List as DUnflatten<Target, NewDims, Index>>::Output;
+
+pub type DUnflattenIndex<List, Target, NewDims, Index> =
+    <List as DUnflatten<Target, NewDims, Index>>::Index;
+
+impl<Target, NewDims, Size, Tail> DUnflatten<Target, NewDims, Current> for DCons<Target, Size, Tail>
+where
+    Target: Dim,
+    NewDims: NonScalarDim + DSizeProduct<Output = Size> + DExtend<Tail>,
+    Size: Unsigned,
+    Tail: DimList,
+    DExtendOutput<NewDims, Tail>: NonScalarDim,
+{
+    type Output = DExtendOutput<NewDims, Tail>;
+    type Index = CountOutput<Current>;
+}
+
+impl<Target, NewDims, Index, NonTarget, Size, Tail> DUnflatten<Target, NewDims, Next<Index>>
+    for DCons<NonTarget, Size, Tail>
+where
+    Target: Dim,
+    NewDims: NonScalarDim,
+    Index: Counter + Count,
+    NonTarget: Dim,
+    Size: DimSize,
+    Tail: NonScalarDim + DUnflatten<Target, NewDims, Index>,
+    Next<Index>: Count,
+{
+    type Output = DCons<NonTarget, Size, DUnflattenOutput<Tail, Target, NewDims, Index>>;
+    type Index = CountOutput<Next<Index>>;
+}
+
 // test
 
 #[cfg(test)]
@@ -287,7 +365,7 @@ mod tests {
     use type_freak::control::IfSameOutput;
     use typenum::consts::*;
 
-    make_dims! {A, B, C, D, New}
+    make_dims! {A, B, C, D, E, New}
 
     type Dims = DimListType! {(A, U3), (B, U2), (C, U5), (D, U7)};
 
@@ -342,6 +420,25 @@ mod tests {
     type Assert9<Index> =
         IfSameOutput<(), Dims9<Index>, DimListType! {(New, U3), (B, U2), (C, U5), (D, U7)}>;
 
+    // DSizeProduct
+    type Assert10 = IfSameOutput<(), DSizeProductOutput<Dims>, U210>;
+    type Assert11 = IfSameOutput<(), DSizeProductOutput<DimListType! {}>, U1>;
+
+    // DUnflatten
+    type Dims12<Index> = DUnflattenOutput<Dims, C, DimListType! {(New, U5), (E, U1)}, Index>;
+    type Index12<Index> = DUnflattenIndex<Dims, C, DimListType! {(New, U5), (E, U1)}, Index>;
+
+    type Assert12<Index> = IfSameOutput<
+        (),
+        Dims12<Index>,
+        DimListType! {(A, U3), (B, U2), (New, U5), (E, U1), (D, U7)},
+    >;
+    type Assert13<From, To, Index> = IfSameOutput<
+        (),
+        DUnflattenOutput<Dims2<From, To>, New, DimListType! {(B, U2), (C, U5)}, Index>,
+        Dims,
+    >;
+
     #[test]
     fn tensor_flatten_test() {
         let _: Assert1<_, _> = ();
@@ -373,5 +470,13 @@ mod tests {
 
         let _: Assert9<_> = ();
         assert_eq!(Index9::USIZE, 0);
+
+        let _: Assert10 = ();
+        let _: Assert11 = ();
+
+        let _: Assert12<_> = ();
+        assert_eq!(Index12::USIZE, 2);
+
+        let _: Assert13<_, _, _> = ();
     }
 }
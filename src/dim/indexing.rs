@@ -1,12 +1,13 @@
 use super::{
-    DCons, DExtractDim, DExtractDimOutput, DReverse, DReverseOutput, Dim, DimList, NonScalarDim,
+    DCons, DExtractDim, DExtractDimOutput, DNil, DReverse, DReverseOutput, Dim, DimList, DimSize,
+    NonScalarDim,
 };
 use std::ops::Sub;
 use type_freak::{
     counter::{Counter, Current, Next},
     list::{
-        LIndexOf, LIndexOfIndex, LIndexOfMany, LIndexOfManyIndexes, LLength, LLengthOutput,
-        LToUsizeVec, TList,
+        LCons, LIndexOf, LIndexOfIndex, LIndexOfMany, LIndexOfManyIndexes, LLength, LLengthOutput,
+        LNil, LToUsizeVec, TList,
     },
 };
 use typenum::{NonZero, Sub1, Unsigned, B1, U0};
@@ -50,13 +51,45 @@ where
     Target: Dim,
     Index: Counter,
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: DimList + DExtractDim,
     DExtractDimOutput<Self>: LIndexOf<Target, Index>,
 {
     type Index = LIndexOfIndex<DExtractDimOutput<Self>, Target, Index>;
 }
 
+// absence of name
+
+mod sealed {
+    use std::marker::PhantomData;
+
+    /// Wraps a counter that can be inferred but not be named outside the crate.
+    pub struct Inferred<Index>(PhantomData<Index>);
+}
+
+use sealed::Inferred;
+
+/// A marker for lists that do not contain `Target`.
+///
+/// The `Index` is inferred automatically and cannot be spelled out. It locates
+/// `Target` in the list prefixed with `Target`, which is ambiguous and fails
+/// to compile if `Target` is already in the list.
+pub trait DNotContain<Target, Index>
+where
+    Target: Dim,
+    Self: DimList,
+{
+}
+
+impl<Target, Index, List> DNotContain<Target, Inferred<Index>> for List
+where
+    Target: Dim,
+    Index: Counter,
+    List: DimList,
+    DCons<Target, U0, List>: DIndexOf<Target, Index>,
+{
+}
+
 // index of many
 
 pub trait DIndexOfMany<Targets, Indexes>
@@ -96,7 +129,7 @@ where
     Index: Counter,
     Self: NonScalarDim,
     Self::Name: Dim,
-    Self::Size: Unsigned,
+    Self::Size: DimSize,
 {
     type Name;
     type Size;
@@ -108,7 +141,7 @@ pub type DDimAtIndexSize<List, Position, Index> = <List as DDimAtIndex<Position,
 impl<Name, Size, Tail> DDimAtIndex<U0, Current> for DCons<Name, Size, Tail>
 where
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: DimList,
 {
     type Name = Name;
@@ -121,7 +154,7 @@ where
     Position: Unsigned + NonZero + Sub<B1>,
     Index: Counter,
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: DimList + DDimAtIndex<Sub1<Position>, Index>,
     Sub1<Position>: Unsigned,
 {
@@ -137,7 +170,7 @@ where
     Index: Counter,
     Self: DimList,
     Self::Name: Dim,
-    Self::Size: Unsigned,
+    Self::Size: DimSize,
 {
     type Name;
     type Size;
@@ -166,7 +199,7 @@ where
     Self: DimList,
     Target: Dim,
     Index: Counter,
-    Self::Output: Unsigned,
+    Self::Output: DimSize,
 {
     type Output;
 }
@@ -174,7 +207,7 @@ where
 impl<Target, Size, Tail> DSizeAt<Target, Current> for DCons<Target, Size, Tail>
 where
     Target: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: DimList,
 {
     type Output = Size;
@@ -186,7 +219,7 @@ where
     Index: Counter,
     Target: Dim,
     NonTarget: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: DimList + DSizeAt<Target, Index>,
 {
     type Output = DSizeAtOutput<Tail, Target, Index>;
@@ -194,6 +227,72 @@ where
 
 pub type DSizeAtOutput<List, Target, Index> = <List as DSizeAt<Target, Index>>::Output;
 
+// select many
+
+/// A type operator that collects `Targets` along with their sizes into
+/// a new list, in the order of `Targets`.
+pub trait DSelectMany<Targets, Indexes>
+where
+    Targets: TList,
+    Indexes: TList,
+    Self: DimList,
+    Self::Output: DimList,
+{
+    type Output;
+}
+
+pub type DSelectManyOutput<List, Targets, Indexes> =
+    <List as DSelectMany<Targets, Indexes>>::Output;
+
+impl<List> DSelectMany<LNil, LNil> for List
+where
+    List: DimList,
+{
+    type Output = DNil;
+}
+
+impl<Target, TRemain, Index, IRemain, List>
+    DSelectMany<LCons<Target, TRemain>, LCons<Index, IRemain>> for List
+where
+    Target: Dim,
+    TRemain: TList,
+    Index: Counter,
+    IRemain: TList,
+    List: DimList + DSizeAt<Target, Index> + DSelectMany<TRemain, IRemain>,
+{
+    type Output = DCons<
+        Target,
+        DSizeAtOutput<List, Target, Index>,
+        DSelectManyOutput<List, TRemain, IRemain>,
+    >;
+}
+
+// contain dimensions
+
+/// A marker for lists that contain every dimension of `Sub` with the
+/// same size, where the dimensions can be in any order.
+pub trait DContainDims<Sub, Indexes>
+where
+    Sub: DimList,
+    Indexes: TList,
+    Self: DimList,
+{
+    /// The indexes of dimensions of `Sub` in the order of `Sub`.
+    fn indexes() -> Vec<usize>;
+}
+
+impl<List, Sub, Indexes> DContainDims<Sub, Indexes> for List
+where
+    Sub: DimList + DExtractDim,
+    Indexes: TList,
+    List: DIndexOfMany<DExtractDimOutput<Sub>, Indexes>
+        + DSelectMany<DExtractDimOutput<Sub>, Indexes, Output = Sub>,
+{
+    fn indexes() -> Vec<usize> {
+        <List as DIndexOfMany<DExtractDimOutput<Sub>, Indexes>>::indexes()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -228,6 +327,12 @@ mod tests {
     type Assert9<Idx> =
         AssertSame<DIndexOfManyIndexes<SomeDims, TListType! {C, A}, Idx>, TListType! {U2, U0}>;
 
+    // select multiple dimensions
+    type Assert10<Idx> = AssertSame<
+        DSelectManyOutput<SomeDims, TListType! {C, A}, Idx>,
+        DimListType! {(C, U4), (A, U3)},
+    >;
+
     // name or size at position
     type Assert21<Idx> = AssertSame<DDimAtIndexName<SomeDims, U1, Idx>, B>;
     type Assert22<Idx> = AssertSame<DDimAtIndexSize<SomeDims, U1, Idx>, U2>;
@@ -245,6 +350,7 @@ mod tests {
         let _: Assert7 = ();
         let _: Assert8 = ();
         let _: Assert9<_> = ();
+        let _: Assert10<_> = ();
         let _: Assert21<_> = ();
         let _: Assert22<_> = ();
         let _: Assert23<_> = ();
@@ -256,4 +362,29 @@ mod tests {
             &[2, 0]
         );
     }
+
+    #[test]
+    fn dim_not_contain_test() {
+        fn assert_not_contain<List, Target, Index>()
+        where
+            Target: Dim,
+            List: DNotContain<Target, Index>,
+        {
+        }
+
+        assert_not_contain::<SomeDims, D, _>();
+        assert_not_contain::<EmptyDims, A, _>();
+
+        // It triggers compile error because B is in the list
+        // assert_not_contain::<SomeDims, B, _>();
+    }
+
+    #[test]
+    fn dim_contain_test() {
+        // contain multiple dimensions
+        assert_eq!(
+            <SomeDims as DContainDims<DimListType! {(C, U4), (A, U3)}, _>>::indexes(),
+            &[2, 0]
+        );
+    }
 }
@@ -1,6 +1,6 @@
-use super::{DCons, DNil, Dim, DimList};
+use super::{DCons, DNil, Dim, DimList, DimSize};
 use type_freak::counter::{Counter, Current, Next};
-use typenum::{Unsigned, U1};
+use typenum::U1;
 
 // insert at
 
@@ -8,11 +8,13 @@ pub trait DInsertAt<Name, Size, Target, Index>
 where
     Self: DimList,
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Target: Dim,
     Index: Counter,
     Self::Output: DimList,
 {
+    const INDEX: usize;
+
     type Output;
 }
 
@@ -20,11 +22,13 @@ impl<NewName, NewSize, Target, Size, Tail> DInsertAt<NewName, NewSize, Target, C
     for DCons<Target, Size, Tail>
 where
     NewName: Dim,
-    NewSize: Unsigned,
+    NewSize: DimSize,
     Target: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: DimList,
 {
+    const INDEX: usize = 0;
+
     type Output = DCons<NewName, NewSize, DCons<Target, Size, Tail>>;
 }
 
@@ -33,12 +37,14 @@ impl<NewName, NewSize, Target, Index, NonTarget, Size, Tail>
 where
     Index: Counter,
     NewName: Dim,
-    NewSize: Unsigned,
+    NewSize: DimSize,
     Target: Dim,
     NonTarget: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: DimList + DInsertAt<NewName, NewSize, Target, Index>,
 {
+    const INDEX: usize = 1 + <Tail as DInsertAt<NewName, NewSize, Target, Index>>::INDEX;
+
     type Output = DCons<NonTarget, Size, DInsertAtOutput<Tail, NewName, NewSize, Target, Index>>;
 }
 
@@ -58,7 +64,7 @@ pub trait DAppend<Name, Size>
 where
     Self: DimList,
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Self::Output: DimList,
 {
     type Output;
@@ -67,7 +73,7 @@ where
 impl<Name, Size> DAppend<Name, Size> for DNil
 where
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
 {
     type Output = DCons<Name, Size, DNil>;
 }
@@ -75,9 +81,9 @@ where
 impl<NewName, NewSize, Name, Size, Tail> DAppend<NewName, NewSize> for DCons<Name, Size, Tail>
 where
     NewName: Dim,
-    NewSize: Unsigned,
+    NewSize: DimSize,
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: DimList + DAppend<NewName, NewSize>,
 {
     type Output = DCons<Name, Size, <Tail as DAppend<NewName, NewSize>>::Output>;
@@ -91,7 +97,7 @@ pub trait DPrepend<Name, Size>
 where
     Self: DimList,
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Self::Output: DimList,
 {
     type Output;
@@ -100,7 +106,7 @@ where
 impl<Name, Size, List> DPrepend<Name, Size> for List
 where
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     List: DimList,
 {
     type Output = DCons<Name, Size, List>;
@@ -165,6 +171,7 @@ mod tests {
 
         // insert single dim
         let _: Assert6<_> = ();
+        assert_eq!(<SomeDims as DInsertAt<D, U5, B, _>>::INDEX, 1);
 
         // expand dim
         let _: Assert7<_> = ();
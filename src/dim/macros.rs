@@ -10,7 +10,9 @@ macro_rules! make_dims {
                 }
             }
 
-            impl $crate::dim::Dim for $name {}
+            impl $crate::dim::Dim for $name {
+                const NAME: &'static str = stringify!($name);
+            }
         )*
     };
 }
@@ -1,9 +1,8 @@
-use super::{DCons, DMarkedCons, Dim, DimList};
+use super::{DCons, DMarkedCons, Dim, DimList, DimSize};
 use type_freak::{
     counter::{Count, CountOutput, Counter, Current, Next},
     list::{LCons, LNil, TList},
 };
-use typenum::Unsigned;
 
 // mark node
 
@@ -22,7 +21,7 @@ pub type DMarkOutput<List, Target, Index> = <List as DMark<Target, Index>>::Outp
 impl<Target, Size, Tail> DMark<Target, Current> for DCons<Target, Size, Tail>
 where
     Target: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: DimList,
 {
     type Output = DMarkedCons<Target, Size, Tail>;
@@ -34,7 +33,7 @@ where
     Target: Dim,
     Index: Counter,
     NonTarget: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: DimList + DMark<Target, Index>,
 {
     type Output = DCons<NonTarget, Size, DMarkOutput<Tail, Target, Index>>;
@@ -46,7 +45,7 @@ where
     Target: Dim,
     Index: Counter,
     NonTarget: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: DimList + DMark<Target, Index>,
 {
     type Output = DMarkedCons<NonTarget, Size, DMarkOutput<Tail, Target, Index>>;
@@ -1,4 +1,4 @@
-use super::{DCons, DNil, Dim, DimList};
+use super::{DCons, DNil, Dim, DimList, DimSize};
 use typenum::Unsigned;
 
 // scalar marker
@@ -12,11 +12,27 @@ pub trait NonScalarDim: DimList {}
 impl<D, S, T> NonScalarDim for DCons<D, S, T>
 where
     D: Dim,
-    S: Unsigned,
+    S: DimSize,
     T: DimList,
 {
 }
 
+// static size marker
+
+/// A marker for lists which sizes are all known in compile time, that is,
+/// containing no [Dyn](super::Dyn) size.
+pub trait StaticDimList: DimList {}
+
+impl StaticDimList for DNil {}
+
+impl<D, S, T> StaticDimList for DCons<D, S, T>
+where
+    D: Dim,
+    S: Unsigned,
+    T: StaticDimList,
+{
+}
+
 // vector marker
 
 pub trait VectorDim: DimList {}
@@ -24,7 +40,7 @@ pub trait VectorDim: DimList {}
 impl<D, S> VectorDim for DCons<D, S, DNil>
 where
     D: Dim,
-    S: Unsigned,
+    S: DimSize,
 {
 }
 
@@ -35,9 +51,9 @@ impl NonVectorDim for DNil {}
 impl<D1, S1, D2, S2, T> NonVectorDim for DCons<D1, S1, DCons<D2, S2, T>>
 where
     D1: Dim,
-    S1: Unsigned,
+    S1: DimSize,
     D2: Dim,
-    S2: Unsigned,
+    S2: DimSize,
     T: DimList,
 {
 }
@@ -49,9 +65,9 @@ pub trait MatrixDim: DimList {}
 impl<D1, S1, D2, S2> MatrixDim for DCons<D1, S1, DCons<D2, S2, DNil>>
 where
     D1: Dim,
-    S1: Unsigned,
+    S1: DimSize,
     D2: Dim,
-    S2: Unsigned,
+    S2: DimSize,
 {
 }
 
@@ -62,18 +78,18 @@ impl NonMatrixDim for DNil {}
 impl<D, S> NonMatrixDim for DCons<D, S, DNil>
 where
     D: Dim,
-    S: Unsigned,
+    S: DimSize,
 {
 }
 
 impl<D1, S1, D2, S2, D3, S3, T> MatrixDim for DCons<D1, S1, DCons<D2, S2, DCons<D3, S3, T>>>
 where
     D1: Dim,
-    S1: Unsigned,
+    S1: DimSize,
     D2: Dim,
-    S2: Unsigned,
+    S2: DimSize,
     D3: Dim,
-    S3: Unsigned,
+    S3: DimSize,
     T: DimList,
 {
 }
@@ -1,10 +1,9 @@
 use super::{
     BcastAbscent, BroadcastMatcher, DBroadcastBothReversely, DBroadcastBothReverselyOutput,
-    DReverse, DReverseOutput, Dim, DimList, MatrixDim,
+    DReverse, DReverseOutput, Dim, DimList, DimSize, MatrixDim,
 };
 
 use crate::{DimListType, DimListTypeWithTail};
-use typenum::Unsigned;
 
 // two-dimensional matrix multiplication
 
@@ -23,11 +22,11 @@ pub type DMatMulOutput<Lhs, Rhs> = <Lhs as DMatMul<Rhs>>::Output;
 impl<MDim, MSize, NDim, NSize, PDim, PSize> DMatMul<DimListType! {(NDim, NSize), (PDim, PSize)}> for DimListType! {(MDim, MSize), (NDim, NSize)}
 where
     MDim: Dim,
-    MSize: Unsigned,
+    MSize: DimSize,
     NDim: Dim,
-    NSize: Unsigned,
+    NSize: DimSize,
     PDim: Dim,
-    PSize: Unsigned,
+    PSize: DimSize,
 {
     type Output = DimListType! {(MDim, MSize), (PDim, PSize)};
 }
@@ -79,7 +78,7 @@ impl<LDim, RDim, Size> DMatMulAuxiliary<DimListType! {(RDim, Size)}, BcastAbscen
 where
     LDim: Dim,
     RDim: Dim,
-    Size: Unsigned,
+    Size: DimSize,
 {
     type Output = DimListType! {};
 }
@@ -89,11 +88,11 @@ impl<MDim, MSize, NDim, NSize, PDim, PSize, LTail, RTail, Matcher>
     DMatMulAuxiliary<DimListTypeWithTail! {(PDim, PSize), (NDim, NSize), RTail}, Matcher> for DimListTypeWithTail! {(NDim, NSize), (MDim, MSize), LTail}
 where
     MDim: Dim,
-    MSize: Unsigned,
+    MSize: DimSize,
     NDim: Dim,
-    NSize: Unsigned,
+    NSize: DimSize,
     PDim: Dim,
-    PSize: Unsigned,
+    PSize: DimSize,
     LTail: DimList + DBroadcastBothReversely<RTail, Matcher>,
     RTail: DimList,
     Matcher: BroadcastMatcher,
@@ -106,9 +105,9 @@ impl<NDim, NSize, PDim, PSize, Tail>
     DMatMulAuxiliary<DimListTypeWithTail! {(PDim, PSize), (NDim, NSize), Tail}, BcastAbscent> for DimListType! {(NDim, NSize)}
 where
     NDim: Dim,
-    NSize: Unsigned,
+    NSize: DimSize,
     PDim: Dim,
-    PSize: Unsigned,
+    PSize: DimSize,
     Tail: DimList,
 {
     type Output = DimListTypeWithTail! {(PDim, PSize), Tail};
@@ -118,9 +117,9 @@ where
 impl<MDim, MSize, NDim, NSize, Tail> DMatMulAuxiliary<DimListType! {(NDim, NSize)}, BcastAbscent> for DimListTypeWithTail! {(NDim, NSize), (MDim, MSize), Tail}
 where
     MDim: Dim,
-    MSize: Unsigned,
+    MSize: DimSize,
     NDim: Dim,
-    NSize: Unsigned,
+    NSize: DimSize,
     Tail: DimList,
 {
     type Output = DimListTypeWithTail! {(MDim, MSize), Tail};
@@ -1,4 +1,6 @@
+mod align;
 mod broadcast;
+mod contract;
 mod flatten;
 mod indexing;
 mod insert;
@@ -6,9 +8,15 @@ mod macros;
 mod mark;
 mod marker;
 mod matmul;
+mod nested;
 mod remove;
+mod replace;
+mod size;
+mod spatial;
 
+pub use align::*;
 pub use broadcast::*;
+pub use contract::*;
 pub use flatten::*;
 pub use indexing::*;
 pub use insert::*;
@@ -16,24 +24,38 @@ pub use macros::*;
 pub use mark::*;
 pub use marker::*;
 pub use matmul::*;
+pub use nested::*;
 pub use remove::*;
+pub use replace::*;
+pub use size::*;
+pub use spatial::*;
 
 use std::marker::PhantomData;
 use type_freak::{
     counter::{Count, CountOutput, Counter, Current, Next},
     list::{LCons, LNil, LSetEqual, LSetEqualOutput, TList},
 };
-use typenum::{Sum, Unsigned, U1};
+use typenum::U1;
 
 // dimension list
 
-pub trait Dim {}
+pub trait Dim {
+    const NAME: &'static str;
+}
 
+/// A type-level list of named dimensions.
+///
+/// The shape methods return the static sizes. A [Dyn] size is reported as
+/// `-1` by [shape_i64](DimList::shape_i64), following the inferred size
+/// convention of libtorch, while [shape_usize](DimList::shape_usize)
+/// panics on it.
 pub trait DimList {
     fn shape_i64() -> Vec<i64>;
     fn shape_usize() -> Vec<usize>;
+    fn names() -> Vec<&'static str>;
     fn append_shape_i64(prev: &mut Vec<i64>);
     fn append_shape_usize(prev: &mut Vec<usize>);
+    fn append_names(prev: &mut Vec<&'static str>);
 }
 
 // end of dim list
@@ -55,9 +77,15 @@ impl DimList for DNil {
         vec![]
     }
 
+    fn names() -> Vec<&'static str> {
+        vec![]
+    }
+
     fn append_shape_usize(_prev: &mut Vec<usize>) {}
 
     fn append_shape_i64(_prev: &mut Vec<i64>) {}
+
+    fn append_names(_prev: &mut Vec<&'static str>) {}
 }
 
 // node of dim list
@@ -65,7 +93,7 @@ impl DimList for DNil {
 pub struct DCons<Name, Size, Tail>
 where
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: DimList,
 {
     _phantom: PhantomData<(Name, Size, Tail)>,
@@ -74,7 +102,7 @@ where
 impl<Name, Size, Tail> DCons<Name, Size, Tail>
 where
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: DimList,
 {
     pub fn new() -> Self {
@@ -87,7 +115,7 @@ where
 impl<Name, Size, Tail> DimList for DCons<Name, Size, Tail>
 where
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: DimList,
 {
     fn shape_usize() -> Vec<usize> {
@@ -102,15 +130,26 @@ where
         shape
     }
 
+    fn names() -> Vec<&'static str> {
+        let mut names = vec![];
+        Self::append_names(&mut names);
+        names
+    }
+
     fn append_shape_usize(prev: &mut Vec<usize>) {
-        prev.push(Size::USIZE);
+        prev.push(Size::SIZE.expect("the dimension size is dynamic"));
         Tail::append_shape_usize(prev);
     }
 
     fn append_shape_i64(prev: &mut Vec<i64>) {
-        prev.push(Size::I64);
+        prev.push(Size::SIZE.map(|size| size as i64).unwrap_or(-1));
         Tail::append_shape_i64(prev);
     }
+
+    fn append_names(prev: &mut Vec<&'static str>) {
+        prev.push(Name::NAME);
+        Tail::append_names(prev);
+    }
 }
 
 // marked node for remove-many op
@@ -118,7 +157,7 @@ where
 pub struct DMarkedCons<Name, Size, Tail>
 where
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: DimList,
 {
     _phantom: PhantomData<(Name, Size, Tail)>,
@@ -127,7 +166,7 @@ where
 impl<Name, Size, Tail> DimList for DMarkedCons<Name, Size, Tail>
 where
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: DimList,
 {
     fn shape_i64() -> Vec<i64> {
@@ -138,6 +177,10 @@ where
         unreachable!();
     }
 
+    fn names() -> Vec<&'static str> {
+        unreachable!();
+    }
+
     fn append_shape_i64(_prev: &mut Vec<i64>) {
         unreachable!();
     }
@@ -145,6 +188,10 @@ where
     fn append_shape_usize(_prev: &mut Vec<usize>) {
         unreachable!();
     }
+
+    fn append_names(_prev: &mut Vec<&'static str>) {
+        unreachable!();
+    }
 }
 
 // extract dimension part
@@ -164,7 +211,7 @@ impl DExtractDim for DNil {
 impl<Name, Size, Tail> DExtractDim for DCons<Name, Size, Tail>
 where
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: DimList + DExtractDim,
 {
     type Output = LCons<Name, DExtractDimOutput<Tail>>;
@@ -188,7 +235,7 @@ where
 impl<Target, Size, Tail> DReduceToOne<Target, Current> for DCons<Target, Size, Tail>
 where
     Target: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: DimList,
 {
     const INDEX: usize = 0;
@@ -201,7 +248,7 @@ where
     Index: Counter,
     Target: Dim,
     NonTarget: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: DimList,
     Tail: DReduceToOne<Target, Index>,
 {
@@ -244,7 +291,7 @@ where
     Target: Dim,
     TRemain: TList,
     SomeDim: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: DimList,
     Self: DReduceToOne<Target, Index>,
     <Self as DReduceToOne<Target, Index>>::Output: DReduceManyToOne<TRemain, IRemain>,
@@ -286,7 +333,7 @@ impl<ReversedTail, Name, Size, Tail> DReverseWithTail<ReversedTail> for DCons<Na
 where
     ReversedTail: DimList,
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: DReverseWithTail<DCons<Name, Size, ReversedTail>>,
 {
     type Output = DReverseWithTailOutput<Tail, DCons<Name, Size, ReversedTail>>;
@@ -372,7 +419,7 @@ where
     Target: Dim,
     TRemain: TList,
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: DimList,
     Self: DSizeAt<Target, Index> + DRemoveAt<Target, Index>,
     <Self as DRemoveAt<Target, Index>>::Output: DPermute<TRemain, IRemain>,
@@ -431,7 +478,7 @@ impl<CurrDim, CurrSize, RTail, LTail> DimListEqual<DCons<CurrDim, CurrSize, RTai
     for DCons<CurrDim, CurrSize, LTail>
 where
     CurrDim: Dim,
-    CurrSize: Unsigned,
+    CurrSize: DimSize,
     RTail: DimList,
     LTail: DimList + DimListEqual<RTail>,
 {
@@ -462,7 +509,7 @@ impl<Rhs, Name, Size, Tail> DExtend<Rhs> for DCons<Name, Size, Tail>
 where
     Rhs: DimList,
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: DimList + DExtend<Rhs>,
 {
     type Output = DCons<Name, Size, DExtendOutput<Tail, Rhs>>;
@@ -488,7 +535,7 @@ impl DCombineEqual<DNil> for DNil {
 impl<Name, Size, LTail, RTail> DCombineEqual<DCons<Name, Size, RTail>> for DCons<Name, Size, LTail>
 where
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     LTail: DimList + DCombineEqual<RTail>,
     RTail: DimList,
 {
@@ -515,16 +562,15 @@ where
 impl<RSize, RTail, Target, LSize, LTail> DConcatAt<DCons<Target, RSize, RTail>, Target, Current>
     for DCons<Target, LSize, LTail>
 where
-    RSize: Unsigned,
+    RSize: DimSize,
     RTail: DimList,
-    LSize: Unsigned + std::ops::Add<RSize>,
+    LSize: DimSize + DSizeAdd<RSize>,
     LTail: DimList + DCombineEqual<RTail>,
     Target: Dim,
-    Sum<LSize, RSize>: Unsigned,
 {
     const INDEX: usize = 0;
 
-    type Output = DCons<Target, Sum<LSize, RSize>, DCombineEqualOutput<LTail, RTail>>;
+    type Output = DCons<Target, DSizeAddOutput<LSize, RSize>, DCombineEqualOutput<LTail, RTail>>;
 }
 
 impl<Index, Name, Size, RTail, Target, LTail>
@@ -532,7 +578,7 @@ impl<Index, Name, Size, RTail, Target, LTail>
 where
     Index: Counter,
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     RTail: DimList,
     LTail: DimList + DConcatAt<RTail, Target, Index>,
     Target: Dim,
@@ -636,5 +682,10 @@ mod tests {
         assert_eq!(SomeDims::shape_usize(), &[3, 2, 4]);
         assert_eq!(AnotherDims::shape_usize(), &[1, 0]);
         assert_eq!(TheOtherDims::shape_usize(), &[3, 4, 4]);
+
+        // dimension names
+        assert_eq!(EmptyDims::names(), Vec::<&str>::new());
+        assert_eq!(SomeDims::names(), &["A", "B", "C"]);
+        assert_eq!(AnotherDims::names(), &["D", "E"]);
     }
 }
@@ -0,0 +1,78 @@
+use super::{DCons, DNil, Dim, DimList};
+use typenum::Unsigned;
+
+// nested vectors
+
+/// A type operator that maps a [DimList] to nested [Vec]s,
+/// which nesting levels follow the dimensions.
+pub trait DNestedVec<Elem>
+where
+    Self: DimList,
+{
+    type Output;
+
+    fn from_elements<Iter>(elements: &mut Iter) -> Self::Output
+    where
+        Iter: Iterator<Item = Elem>;
+}
+
+pub type DNestedVecOutput<List, Elem> = <List as DNestedVec<Elem>>::Output;
+
+impl<Elem> DNestedVec<Elem> for DNil {
+    type Output = Elem;
+
+    fn from_elements<Iter>(elements: &mut Iter) -> Self::Output
+    where
+        Iter: Iterator<Item = Elem>,
+    {
+        elements.next().unwrap()
+    }
+}
+
+impl<Name, Size, Tail, Elem> DNestedVec<Elem> for DCons<Name, Size, Tail>
+where
+    Name: Dim,
+    Size: Unsigned,
+    Tail: DimList + DNestedVec<Elem>,
+{
+    type Output = Vec<DNestedVecOutput<Tail, Elem>>;
+
+    fn from_elements<Iter>(elements: &mut Iter) -> Self::Output
+    where
+        Iter: Iterator<Item = Elem>,
+    {
+        (0..Size::USIZE)
+            .map(|_| Tail::from_elements(elements))
+            .collect()
+    }
+}
+
+// tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{make_dims, DimListType};
+    use type_freak::control::IfSameOutput;
+    use typenum::consts::*;
+
+    make_dims! {A, B}
+
+    type AssertSame<Lhs, Rhs> = IfSameOutput<(), Lhs, Rhs>;
+
+    type Assert1 = AssertSame<DNestedVecOutput<DimListType! {}, i64>, i64>;
+    type Assert2 =
+        AssertSame<DNestedVecOutput<DimListType! {(A, U2), (B, U3)}, i64>, Vec<Vec<i64>>>;
+
+    #[test]
+    fn dim_nested_vec_test() {
+        let _: Assert1 = ();
+        let _: Assert2 = ();
+
+        let mut elements = 0..6;
+        assert_eq!(
+            <DimListType! {(A, U2), (B, U3)} as DNestedVec<i64>>::from_elements(&mut elements),
+            vec![vec![0, 1, 2], vec![3, 4, 5]]
+        );
+    }
+}
@@ -1,10 +1,12 @@
-use super::{DCons, DMarkMany, DMarkManyOutput, DMarkedCons, DNil, Dim, DimList, NonScalarDim};
+use super::{
+    DCons, DMarkMany, DMarkManyOutput, DMarkedCons, DNil, Dim, DimList, DimSize, Dyn, NonScalarDim,
+};
 use std::ops::Sub;
 use type_freak::{
     counter::{Counter, Current, Next},
     list::TList,
 };
-use typenum::{NonZero, Sub1, Unsigned, B1, U0};
+use typenum::{Bit, NonZero, Sub1, UInt, Unsigned, B1, U0, U1};
 
 // remove at
 
@@ -23,7 +25,7 @@ where
 impl<Target, Size, Tail> DRemoveAt<Target, Current> for DCons<Target, Size, Tail>
 where
     Target: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: DimList,
 {
     type Output = Tail;
@@ -39,7 +41,7 @@ where
     Index: Counter,
     Target: Dim,
     NonTarget: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: DimList + DRemoveAt<Target, Index>,
 {
     type Output = DCons<NonTarget, Size, DRemoveAtOutput<Tail, Target, Index>>;
@@ -68,7 +70,7 @@ impl DRemoveMarked for DNil {
 impl<Name, Size, Tail> DRemoveMarked for DCons<Name, Size, Tail>
 where
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: DimList + DRemoveMarked,
 {
     type Output = DCons<Name, Size, DRemoveMarkedOutput<Tail>>;
@@ -77,7 +79,7 @@ where
 impl<Name, Size, Tail> DRemoveMarked for DMarkedCons<Name, Size, Tail>
 where
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: DimList + DRemoveMarked,
 {
     type Output = DRemoveMarkedOutput<Tail>;
@@ -150,7 +152,7 @@ where
     EndPos: Unsigned + Sub<B1>,
     EndIndex: Counter,
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: NonScalarDim + DRemoveByRange<Sub1<BeginPos>, Sub1<EndPos>, BeginIndex, EndIndex>,
     Sub1<BeginPos>: Unsigned,
     Sub1<EndPos>: Unsigned,
@@ -180,7 +182,7 @@ pub type DRemovingByRangeOutput<List, EndPos, EndIndex> =
 impl<Name, Size, Tail> DRemovingByRange<U0, Current> for DCons<Name, Size, Tail>
 where
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: DimList,
 {
     type Output = Tail;
@@ -192,7 +194,7 @@ where
     EndPos: Unsigned + NonZero + Sub<B1>,
     EndIndex: Counter,
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: DimList + DRemovingByRange<Sub1<EndPos>, EndIndex>,
     Sub1<EndPos>: Unsigned,
 {
@@ -217,7 +219,7 @@ pub type DRemoveByRangeToOutput<List, EndPos, EndIndex> =
 impl<Name, Size, Tail> DRemoveByRangeTo<U0, Current> for DCons<Name, Size, Tail>
 where
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: DimList,
 {
     type Output = Tail;
@@ -229,7 +231,7 @@ where
     EndPos: Unsigned + Sub<B1>,
     EndIndex: Counter,
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: NonScalarDim + DRemoveByRangeTo<Sub1<EndPos>, EndIndex>,
     Sub1<EndPos>: Unsigned,
 {
@@ -264,13 +266,100 @@ where
     BeginPos: Unsigned + NonZero + Sub<B1>,
     BeginIndex: Counter,
     Name: Dim,
-    Size: Unsigned,
+    Size: DimSize,
     Tail: NonScalarDim + DRemoveByRangeFrom<Sub1<BeginPos>, BeginIndex>,
     Sub1<BeginPos>: Unsigned,
 {
     type Output = DCons<Name, Size, DRemoveByRangeFromOutput<Tail, Sub1<BeginPos>, BeginIndex>>;
 }
 
+// remove all size-one dimensions
+
+/// A type operator that removes every dimension of static size one.
+/// Dimensions of [Dyn] size are kept even if the runtime size is one.
+pub trait DSqueezeAll
+where
+    Self: DimList,
+    Self::Output: DimList,
+{
+    type Output;
+
+    /// The indexes of removed dimensions in increasing order.
+    fn indexes() -> Vec<usize>;
+}
+
+/// Shifts the indexes of the tail by one.
+fn tail_squeeze_indexes<Tail>() -> Vec<usize>
+where
+    Tail: DSqueezeAll,
+{
+    Tail::indexes().into_iter().map(|index| index + 1).collect()
+}
+
+pub type DSqueezeAllOutput<List> = <List as DSqueezeAll>::Output;
+
+impl DSqueezeAll for DNil {
+    type Output = DNil;
+
+    fn indexes() -> Vec<usize> {
+        vec![]
+    }
+}
+
+impl<Name, Tail> DSqueezeAll for DCons<Name, U0, Tail>
+where
+    Name: Dim,
+    Tail: DimList + DSqueezeAll,
+{
+    type Output = DCons<Name, U0, DSqueezeAllOutput<Tail>>;
+
+    fn indexes() -> Vec<usize> {
+        tail_squeeze_indexes::<Tail>()
+    }
+}
+
+impl<Name, Tail> DSqueezeAll for DCons<Name, U1, Tail>
+where
+    Name: Dim,
+    Tail: DimList + DSqueezeAll,
+{
+    type Output = DSqueezeAllOutput<Tail>;
+
+    fn indexes() -> Vec<usize> {
+        let mut indexes = tail_squeeze_indexes::<Tail>();
+        indexes.insert(0, 0);
+        indexes
+    }
+}
+
+impl<Name, Higher, HigherBit, LowerBit, Tail> DSqueezeAll
+    for DCons<Name, UInt<UInt<Higher, HigherBit>, LowerBit>, Tail>
+where
+    Name: Dim,
+    Higher: Unsigned,
+    HigherBit: Bit,
+    LowerBit: Bit,
+    Tail: DimList + DSqueezeAll,
+{
+    type Output = DCons<Name, UInt<UInt<Higher, HigherBit>, LowerBit>, DSqueezeAllOutput<Tail>>;
+
+    fn indexes() -> Vec<usize> {
+        tail_squeeze_indexes::<Tail>()
+    }
+}
+
+impl<Name, Tail> DSqueezeAll for DCons<Name, Dyn, Tail>
+where
+    Name: Dim,
+    Tail: DimList + DSqueezeAll,
+{
+    type Output = DCons<Name, Dyn, DSqueezeAllOutput<Tail>>;
+
+    fn indexes() -> Vec<usize> {
+        tail_squeeze_indexes::<Tail>()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -282,6 +371,8 @@ mod tests {
 
     type SomeDims = DimListType! {(A, U3), (B, U2), (C, U4)};
 
+    type SqueezableDims = DimListType! {(A, U1), (B, U2), (C, U1), (D, U0), (E, U1)};
+
     type AssertSame<Lhs, Rhs> = IfSameOutput<(), Lhs, Rhs>;
 
     type Assert9<Idx> =
@@ -293,6 +384,10 @@ mod tests {
     type Assert11<Idx> =
         AssertSame<DRemoveManyOutput<SomeDims, TListType! {C, A, B}, Idx>, DimListType! {}>;
 
+    type Assert12 = AssertSame<DSqueezeAllOutput<SomeDims>, SomeDims>;
+
+    type Assert13 = AssertSame<DSqueezeAllOutput<SqueezableDims>, DimListType! {(B, U2), (D, U0)}>;
+
     #[test]
     fn dim_test() {
         // remove single dim
@@ -304,6 +399,12 @@ mod tests {
         // remove until empty
         let _: Assert11<_> = ();
 
+        // remove size-one dims
+        let _: Assert12 = ();
+        let _: Assert13 = ();
+        assert_eq!(<SqueezableDims as DSqueezeAll>::indexes(), &[0, 2, 4]);
+        assert_eq!(<SomeDims as DSqueezeAll>::indexes(), Vec::<usize>::new());
+
         // TODO: remove range test
     }
 }
@@ -0,0 +1,301 @@
+use super::{DCons, DNil, DNotContain, DSizeAt, DSizeAtOutput, Dim, DimList, DimSize};
+use type_freak::counter::{Counter, Current, Next};
+use typenum::{Diff, Quot, Sub1, Sum};
+
+// replace at
+
+/// A type operator that replaces the `Target` dimension with `NewName`
+/// of size `NewSize`.
+pub trait DReplaceAt<Target, NewName, NewSize, Index>
+where
+    Target: Dim,
+    NewName: Dim,
+    NewSize: DimSize,
+    Index: Counter,
+    Self: DimList,
+    Self::Output: DimList,
+{
+    const INDEX: usize;
+
+    type Output;
+}
+
+pub type DReplaceAtOutput<List, Target, NewName, NewSize, Index> =
+    <List as DReplaceAt<Target, NewName, NewSize, Index>>::Output;
+
+impl<Target, NewName, NewSize, Size, Tail> DReplaceAt<Target, NewName, NewSize, Current>
+    for DCons<Target, Size, Tail>
+where
+    Target: Dim,
+    NewName: Dim,
+    NewSize: DimSize,
+    Size: DimSize,
+    Tail: DimList,
+{
+    const INDEX: usize = 0;
+
+    type Output = DCons<NewName, NewSize, Tail>;
+}
+
+impl<Target, NewName, NewSize, Index, NonTarget, Size, Tail>
+    DReplaceAt<Target, NewName, NewSize, Next<Index>> for DCons<NonTarget, Size, Tail>
+where
+    Target: Dim,
+    NewName: Dim,
+    NewSize: DimSize,
+    Index: Counter,
+    NonTarget: Dim,
+    Size: DimSize,
+    Tail: DimList + DReplaceAt<Target, NewName, NewSize, Index>,
+{
+    const INDEX: usize = 1 + <Tail as DReplaceAt<Target, NewName, NewSize, Index>>::INDEX;
+
+    type Output = DCons<NonTarget, Size, DReplaceAtOutput<Tail, Target, NewName, NewSize, Index>>;
+}
+
+// replace last
+
+/// A type operator that replaces the last dimension, which must be `Name`
+/// of size `Size`, with `NewName` of size `NewSize`.
+pub trait DReplaceLast<Name, Size, NewName, NewSize>
+where
+    Name: Dim,
+    Size: DimSize,
+    NewName: Dim,
+    NewSize: DimSize,
+    Self: DimList,
+    Self::Output: DimList,
+{
+    type Output;
+}
+
+pub type DReplaceLastOutput<List, Name, Size, NewName, NewSize> =
+    <List as DReplaceLast<Name, Size, NewName, NewSize>>::Output;
+
+impl<Name, Size, NewName, NewSize> DReplaceLast<Name, Size, NewName, NewSize>
+    for DCons<Name, Size, DNil>
+where
+    Name: Dim,
+    Size: DimSize,
+    NewName: Dim,
+    NewSize: DimSize,
+{
+    type Output = DCons<NewName, NewSize, DNil>;
+}
+
+impl<Name, Size, NewName, NewSize, HeadName, HeadSize, NextName, NextSize, Tail>
+    DReplaceLast<Name, Size, NewName, NewSize>
+    for DCons<HeadName, HeadSize, DCons<NextName, NextSize, Tail>>
+where
+    Name: Dim,
+    Size: DimSize,
+    NewName: Dim,
+    NewSize: DimSize,
+    HeadName: Dim,
+    HeadSize: DimSize,
+    NextName: Dim,
+    NextSize: DimSize,
+    Tail: DimList,
+    DCons<NextName, NextSize, Tail>: DReplaceLast<Name, Size, NewName, NewSize>,
+{
+    type Output = DCons<
+        HeadName,
+        HeadSize,
+        DReplaceLastOutput<DCons<NextName, NextSize, Tail>, Name, Size, NewName, NewSize>,
+    >;
+}
+
+// resize at
+
+pub type DResizeAtOutput<List, Target, NewSize, Index> =
+    DReplaceAtOutput<List, Target, Target, NewSize, Index>;
+
+// rename
+
+/// A type operator that renames the `Old` dimension to `New` while keeping
+/// its size.
+///
+/// The `Index` is a pair of indexes, the first locates `Old` in the input,
+/// and the second checks that `New` is not in the input by [DNotContain],
+/// so that renaming to an existing name fails to compile.
+pub trait DRename<Old, New, Index>
+where
+    Old: Dim,
+    New: Dim,
+    Self: DimList,
+    Self::Output: DimList,
+{
+    const INDEX: usize;
+
+    type Output;
+}
+
+pub type DRenameOutput<List, Old, New, Index> = <List as DRename<Old, New, Index>>::Output;
+
+impl<Old, New, OldIndex, NewIndex, List> DRename<Old, New, (OldIndex, NewIndex)> for List
+where
+    Old: Dim,
+    New: Dim,
+    OldIndex: Counter,
+    List: DimList
+        + DNotContain<New, NewIndex>
+        + DSizeAt<Old, OldIndex>
+        + DReplaceAt<Old, New, DSizeAtOutput<List, Old, OldIndex>, OldIndex>,
+{
+    const INDEX: usize =
+        <List as DReplaceAt<Old, New, DSizeAtOutput<List, Old, OldIndex>, OldIndex>>::INDEX;
+
+    type Output = DReplaceAtOutput<List, Old, New, DSizeAtOutput<List, Old, OldIndex>, OldIndex>;
+}
+
+// index select
+
+/// A type operator that replaces the `Target` dimension with the only
+/// dimension of the index list `IndexDims`.
+pub trait DIndexSelect<Target, IndexDims, Index>
+where
+    Target: Dim,
+    IndexDims: DimList,
+    Index: Counter,
+    Self: DimList,
+    Self::Output: DimList,
+{
+    const INDEX: usize;
+
+    type Output;
+}
+
+pub type DIndexSelectOutput<List, Target, IndexDims, Index> =
+    <List as DIndexSelect<Target, IndexDims, Index>>::Output;
+
+impl<Target, NewName, NewSize, Index, List>
+    DIndexSelect<Target, DCons<NewName, NewSize, DNil>, Index> for List
+where
+    Target: Dim,
+    NewName: Dim,
+    NewSize: DimSize,
+    Index: Counter,
+    List: DimList + DReplaceAt<Target, NewName, NewSize, Index>,
+{
+    const INDEX: usize = <List as DReplaceAt<Target, NewName, NewSize, Index>>::INDEX;
+
+    type Output = DReplaceAtOutput<List, Target, NewName, NewSize, Index>;
+}
+
+// gather
+
+/// A marker trait that `IndexDims` agrees with the list except for the size
+/// of `Target` dimension.
+pub trait DGatherAt<Target, IndexDims, Index>
+where
+    Target: Dim,
+    IndexDims: DimList,
+    Index: Counter,
+    Self: DimList,
+{
+    const INDEX: usize;
+}
+
+impl<Target, Size, IndexSize, Tail> DGatherAt<Target, DCons<Target, IndexSize, Tail>, Current>
+    for DCons<Target, Size, Tail>
+where
+    Target: Dim,
+    Size: DimSize,
+    IndexSize: DimSize,
+    Tail: DimList,
+{
+    const INDEX: usize = 0;
+}
+
+impl<Target, Index, NonTarget, Size, Tail, IndexTail>
+    DGatherAt<Target, DCons<NonTarget, Size, IndexTail>, Next<Index>>
+    for DCons<NonTarget, Size, Tail>
+where
+    Target: Dim,
+    Index: Counter,
+    NonTarget: Dim,
+    Size: DimSize,
+    IndexTail: DimList,
+    Tail: DimList + DGatherAt<Target, IndexTail, Index>,
+{
+    const INDEX: usize = 1 + <Tail as DGatherAt<Target, IndexTail, Index>>::INDEX;
+}
+
+// size of strided slice
+
+/// The number of elements in range from `Start` to `End` exclusively
+/// with step `Step`.
+pub type DSliceSize<Start, End, Step> = Quot<Sub1<Sum<Diff<End, Start>, Step>>, Step>;
+
+// tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{make_dims, DimListType};
+    use type_freak::control::IfSameOutput;
+    use typenum::consts::*;
+
+    make_dims! {A, B, C, D}
+
+    type SomeDims = DimListType! {(A, U3), (B, U2), (C, U4)};
+
+    type AssertSame<Lhs, Rhs> = IfSameOutput<(), Lhs, Rhs>;
+
+    type Assert1<Idx> = AssertSame<
+        DReplaceAtOutput<SomeDims, B, D, U5, Idx>,
+        DimListType! {(A, U3), (D, U5), (C, U4)},
+    >;
+
+    type Assert2<Idx> =
+        AssertSame<DResizeAtOutput<SomeDims, C, U1, Idx>, DimListType! {(A, U3), (B, U2), (C, U1)}>;
+
+    type Assert6<Idx> =
+        AssertSame<DRenameOutput<SomeDims, A, D, Idx>, DimListType! {(D, U3), (B, U2), (C, U4)}>;
+
+    type Assert7<Idx> = AssertSame<
+        DIndexSelectOutput<SomeDims, C, DimListType! {(D, U7)}, Idx>,
+        DimListType! {(A, U3), (B, U2), (D, U7)},
+    >;
+
+    type Assert8 = AssertSame<
+        DReplaceLastOutput<SomeDims, C, U4, D, U9>,
+        DimListType! {(A, U3), (B, U2), (D, U9)},
+    >;
+
+    type Assert3 = AssertSame<DSliceSize<U1, U8, U3>, U3>;
+    type Assert4 = AssertSame<DSliceSize<U0, U6, U2>, U3>;
+    type Assert5 = AssertSame<DSliceSize<U2, U2, U1>, U0>;
+
+    #[test]
+    fn dim_replace_test() {
+        let _: Assert1<_> = ();
+        assert_eq!(<SomeDims as DReplaceAt<B, D, U5, _>>::INDEX, 1);
+
+        let _: Assert2<_> = ();
+        assert_eq!(<SomeDims as DReplaceAt<C, C, U1, _>>::INDEX, 2);
+
+        let _: Assert3 = ();
+        let _: Assert4 = ();
+        let _: Assert5 = ();
+
+        let _: Assert6<_> = ();
+        assert_eq!(<SomeDims as DRename<C, D, _>>::INDEX, 2);
+
+        let _: Assert8 = ();
+
+        let _: Assert7<_> = ();
+        assert_eq!(
+            <SomeDims as DIndexSelect<C, DimListType! {(D, U7)}, _>>::INDEX,
+            2
+        );
+
+        assert_eq!(
+            <SomeDims as DGatherAt<B, DimListType! {(A, U3), (B, U9), (C, U4)}, _>>::INDEX,
+            1
+        );
+
+        // It triggers compile error because B already exists
+        // assert_eq!(<SomeDims as DRename<A, B, _>>::INDEX, 0);
+    }
+}
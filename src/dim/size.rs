@@ -0,0 +1,94 @@
+use std::ops::Add;
+use typenum::{Sum, Unsigned};
+
+// size of dimension
+
+/// The size of a dimension, which is either a typenum [Unsigned] known in
+/// compile time, or [Dyn] known only in runtime.
+pub trait DimSize {
+    /// The static size, or `None` if the size is dynamic.
+    const SIZE: Option<usize>;
+}
+
+impl<Size> DimSize for Size
+where
+    Size: Unsigned,
+{
+    const SIZE: Option<usize> = Some(Size::USIZE);
+}
+
+/// The size marker for dimensions which sizes are determined in runtime.
+///
+/// The actual size is held by the underlying tensor and checked when
+/// the tensor is constructed, for example, by
+/// [try_from_tch](crate::tensor::NamedTensor::try_from_tch).
+pub struct Dyn;
+
+impl DimSize for Dyn {
+    const SIZE: Option<usize> = None;
+}
+
+// sum of sizes
+
+/// A type operator that adds two sizes. The sum is [Dyn] if either
+/// of them is [Dyn].
+pub trait DSizeAdd<Rhs>
+where
+    Rhs: DimSize,
+    Self: DimSize,
+    Self::Output: DimSize,
+{
+    type Output;
+}
+
+pub type DSizeAddOutput<Lhs, Rhs> = <Lhs as DSizeAdd<Rhs>>::Output;
+
+impl<Lhs, Rhs> DSizeAdd<Rhs> for Lhs
+where
+    Lhs: Unsigned + Add<Rhs>,
+    Rhs: Unsigned,
+    Sum<Lhs, Rhs>: Unsigned,
+{
+    type Output = Sum<Lhs, Rhs>;
+}
+
+impl<Rhs> DSizeAdd<Rhs> for Dyn
+where
+    Rhs: DimSize,
+{
+    type Output = Dyn;
+}
+
+impl<Lhs> DSizeAdd<Dyn> for Lhs
+where
+    Lhs: Unsigned,
+{
+    type Output = Dyn;
+}
+
+// tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use type_freak::control::IfSameOutput;
+    use typenum::consts::*;
+
+    type AssertSame<Lhs, Rhs> = IfSameOutput<(), Lhs, Rhs>;
+
+    type Assert1 = AssertSame<DSizeAddOutput<U3, U4>, U7>;
+    type Assert2 = AssertSame<DSizeAddOutput<Dyn, U4>, Dyn>;
+    type Assert3 = AssertSame<DSizeAddOutput<U3, Dyn>, Dyn>;
+    type Assert4 = AssertSame<DSizeAddOutput<Dyn, Dyn>, Dyn>;
+
+    #[test]
+    fn dim_size_test() {
+        let _: Assert1 = ();
+        let _: Assert2 = ();
+        let _: Assert3 = ();
+        let _: Assert4 = ();
+
+        assert_eq!(<U5 as DimSize>::SIZE, Some(5));
+        assert_eq!(<Dyn as DimSize>::SIZE, None);
+    }
+}
@@ -0,0 +1,234 @@
+use super::{DCons, DNil, Dim, DimList, DimSize};
+use crate::make_dims;
+use std::ops::{Add, Div, Mul, Sub};
+use typenum::{Add1, Diff, NonZero, Prod, Quot, Sub1, Sum, Unsigned, B1, U1, U2};
+
+// output size of convolution
+
+/// A type operator that computes the output size of convolution or pooling
+/// along one spatial dimension, that is
+/// `(Size + 2 * Padding - Dilation * (Kernel - 1) - 1) / Stride + 1`.
+pub trait DConvSize<Kernel, Stride, Padding, Dilation>
+where
+    Kernel: Unsigned + NonZero,
+    Stride: Unsigned + NonZero,
+    Padding: Unsigned,
+    Dilation: Unsigned + NonZero,
+    Self: Unsigned,
+    Self::Output: Unsigned,
+{
+    type Output;
+}
+
+pub type DConvSizeOutput<Size, Kernel, Stride, Padding, Dilation> =
+    <Size as DConvSize<Kernel, Stride, Padding, Dilation>>::Output;
+
+/// The size padded on both sides.
+pub type DPaddedSize<Size, Padding> = Sum<Size, Prod<U2, Padding>>;
+
+/// The span of a dilated kernel minus one.
+pub type DDilatedSpan<Kernel, Dilation> = Prod<Dilation, Sub1<Kernel>>;
+
+impl<Size, Kernel, Stride, Padding, Dilation> DConvSize<Kernel, Stride, Padding, Dilation> for Size
+where
+    Size: Unsigned + Add<Prod<U2, Padding>>,
+    Kernel: Unsigned + NonZero + Sub<B1>,
+    Stride: Unsigned + NonZero,
+    Padding: Unsigned,
+    Dilation: Unsigned + NonZero + Mul<Sub1<Kernel>>,
+    U2: Mul<Padding>,
+    DPaddedSize<Size, Padding>: Sub<DDilatedSpan<Kernel, Dilation>>,
+    Diff<DPaddedSize<Size, Padding>, DDilatedSpan<Kernel, Dilation>>: Sub<B1>,
+    Sub1<Diff<DPaddedSize<Size, Padding>, DDilatedSpan<Kernel, Dilation>>>: Div<Stride>,
+    Quot<Sub1<Diff<DPaddedSize<Size, Padding>, DDilatedSpan<Kernel, Dilation>>>, Stride>: Add<B1>,
+    Add1<Quot<Sub1<Diff<DPaddedSize<Size, Padding>, DDilatedSpan<Kernel, Dilation>>>, Stride>>:
+        Unsigned,
+{
+    type Output =
+        Add1<Quot<Sub1<Diff<DPaddedSize<Size, Padding>, DDilatedSpan<Kernel, Dilation>>>, Stride>>;
+}
+
+// last two spatial dimensions
+
+/// A type operator that reads the sizes of the last two dimensions, which
+/// must be `Height` and `Width` in order.
+pub trait DSpatial2d<Height, Width>
+where
+    Height: Dim,
+    Width: Dim,
+    Self: DimList,
+    Self::HeightSize: DimSize,
+    Self::WidthSize: DimSize,
+{
+    type HeightSize;
+    type WidthSize;
+}
+
+pub type DSpatial2dHeight<List, Height, Width> = <List as DSpatial2d<Height, Width>>::HeightSize;
+pub type DSpatial2dWidth<List, Height, Width> = <List as DSpatial2d<Height, Width>>::WidthSize;
+
+impl<Height, HeightSize, Width, WidthSize> DSpatial2d<Height, Width>
+    for DCons<Height, HeightSize, DCons<Width, WidthSize, DNil>>
+where
+    Height: Dim,
+    HeightSize: DimSize,
+    Width: Dim,
+    WidthSize: DimSize,
+{
+    type HeightSize = HeightSize;
+    type WidthSize = WidthSize;
+}
+
+impl<Height, Width, Name, Size, NextName, NextSize, LastName, LastSize, Tail>
+    DSpatial2d<Height, Width>
+    for DCons<Name, Size, DCons<NextName, NextSize, DCons<LastName, LastSize, Tail>>>
+where
+    Height: Dim,
+    Width: Dim,
+    Name: Dim,
+    Size: DimSize,
+    NextName: Dim,
+    NextSize: DimSize,
+    LastName: Dim,
+    LastSize: DimSize,
+    Tail: DimList,
+    DCons<NextName, NextSize, DCons<LastName, LastSize, Tail>>: DSpatial2d<Height, Width>,
+{
+    type HeightSize =
+        DSpatial2dHeight<DCons<NextName, NextSize, DCons<LastName, LastSize, Tail>>, Height, Width>;
+    type WidthSize =
+        DSpatial2dWidth<DCons<NextName, NextSize, DCons<LastName, LastSize, Tail>>, Height, Width>;
+}
+
+// resize last two spatial dimensions
+
+/// A type operator that resizes the last two dimensions, which must be
+/// `Height` and `Width` in order, to `NewHeightSize` and `NewWidthSize`.
+pub trait DResizeSpatial2d<Height, Width, NewHeightSize, NewWidthSize>
+where
+    Height: Dim,
+    Width: Dim,
+    NewHeightSize: DimSize,
+    NewWidthSize: DimSize,
+    Self: DimList,
+    Self::Output: DimList,
+{
+    type Output;
+}
+
+pub type DResizeSpatial2dOutput<List, Height, Width, NewHeightSize, NewWidthSize> =
+    <List as DResizeSpatial2d<Height, Width, NewHeightSize, NewWidthSize>>::Output;
+
+impl<Height, HeightSize, Width, WidthSize, NewHeightSize, NewWidthSize>
+    DResizeSpatial2d<Height, Width, NewHeightSize, NewWidthSize>
+    for DCons<Height, HeightSize, DCons<Width, WidthSize, DNil>>
+where
+    Height: Dim,
+    HeightSize: DimSize,
+    Width: Dim,
+    WidthSize: DimSize,
+    NewHeightSize: DimSize,
+    NewWidthSize: DimSize,
+{
+    type Output = DCons<Height, NewHeightSize, DCons<Width, NewWidthSize, DNil>>;
+}
+
+impl<
+        Height,
+        Width,
+        NewHeightSize,
+        NewWidthSize,
+        Name,
+        Size,
+        NextName,
+        NextSize,
+        LastName,
+        LastSize,
+        Tail,
+    > DResizeSpatial2d<Height, Width, NewHeightSize, NewWidthSize>
+    for DCons<Name, Size, DCons<NextName, NextSize, DCons<LastName, LastSize, Tail>>>
+where
+    Height: Dim,
+    Width: Dim,
+    NewHeightSize: DimSize,
+    NewWidthSize: DimSize,
+    Name: Dim,
+    Size: DimSize,
+    NextName: Dim,
+    NextSize: DimSize,
+    LastName: Dim,
+    LastSize: DimSize,
+    Tail: DimList,
+    DCons<NextName, NextSize, DCons<LastName, LastSize, Tail>>:
+        DResizeSpatial2d<Height, Width, NewHeightSize, NewWidthSize>,
+{
+    type Output = DCons<
+        Name,
+        Size,
+        DResizeSpatial2dOutput<
+            DCons<NextName, NextSize, DCons<LastName, LastSize, Tail>>,
+            Height,
+            Width,
+            NewHeightSize,
+            NewWidthSize,
+        >,
+    >;
+}
+
+// output dimensions of 2D pooling
+
+/// The dimensions after pooling `Height` and `Width` with a square window.
+pub type DPool2dOutput<List, Height, Width, Kernel, Stride, Padding> = DResizeSpatial2dOutput<
+    List,
+    Height,
+    Width,
+    DConvSizeOutput<DSpatial2dHeight<List, Height, Width>, Kernel, Stride, Padding, U1>,
+    DConvSizeOutput<DSpatial2dWidth<List, Height, Width>, Kernel, Stride, Padding, U1>,
+>;
+
+// dimensions of convolution weights
+
+make_dims! {OutChannel, InChannel, KernelHeight, KernelWidth}
+
+// tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DimListType;
+    use type_freak::control::IfSameOutput;
+    use typenum::consts::*;
+
+    make_dims! {Batch, Channel, Height, Width}
+
+    type ImageDims = DimListType! {(Batch, U2), (Channel, U3), (Height, U32), (Width, U28)};
+
+    type AssertSame<Lhs, Rhs> = IfSameOutput<(), Lhs, Rhs>;
+
+    type Assert1 = AssertSame<DConvSizeOutput<U32, U3, U1, U1, U1>, U32>;
+    type Assert2 = AssertSame<DConvSizeOutput<U32, U3, U2, U1, U1>, U16>;
+    type Assert3 = AssertSame<DConvSizeOutput<U28, U5, U1, U0, U2>, U20>;
+    type Assert4 = AssertSame<DSpatial2dHeight<ImageDims, Height, Width>, U32>;
+    type Assert5 = AssertSame<DSpatial2dWidth<ImageDims, Height, Width>, U28>;
+    type Assert6 = AssertSame<
+        DResizeSpatial2dOutput<ImageDims, Height, Width, U7, U7>,
+        DimListType! {(Batch, U2), (Channel, U3), (Height, U7), (Width, U7)},
+    >;
+    type Assert7 = AssertSame<
+        DPool2dOutput<ImageDims, Height, Width, U2, U2, U0>,
+        DimListType! {(Batch, U2), (Channel, U3), (Height, U16), (Width, U14)},
+    >;
+
+    #[test]
+    fn dim_spatial_test() {
+        let _: Assert1 = ();
+        let _: Assert2 = ();
+        let _: Assert3 = ();
+
+        // spatial dims
+        let _: Assert4 = ();
+        let _: Assert5 = ();
+        let _: Assert6 = ();
+        let _: Assert7 = ();
+    }
+}
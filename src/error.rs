@@ -0,0 +1,76 @@
+use std::{error::Error, fmt};
+use tch::{Device, Kind};
+
+// shape error
+
+/// The error returned when a [tch::Tensor] does not agree with
+/// the typed properties of a [NamedTensor](crate::tensor::NamedTensor).
+#[derive(Debug, Clone, PartialEq)]
+pub enum ShapeError {
+    /// The number of dimensions differs.
+    RankMismatch { expected: usize, actual: usize },
+    /// The size of the dimension `name` at `index` differs.
+    SizeMismatch {
+        name: &'static str,
+        index: usize,
+        expected: i64,
+        actual: i64,
+    },
+    /// The number of elements differs.
+    LengthMismatch { expected: usize, actual: usize },
+    /// The number of dynamic sizes differs from that of dynamic dimensions.
+    DynCountMismatch { expected: usize, actual: usize },
+    /// The dynamic size of the dimension `name` at `index` is negative.
+    NegativeSize {
+        name: &'static str,
+        index: usize,
+        actual: i64,
+    },
+    /// The data type differs.
+    KindMismatch { expected: Kind, actual: Kind },
+    /// The device differs.
+    DeviceMismatch { expected: Device, actual: Device },
+}
+
+impl fmt::Display for ShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ShapeError::RankMismatch { expected, actual } => {
+                write!(f, "expect {} dimensions, but found {}", expected, actual)
+            }
+            ShapeError::SizeMismatch {
+                name,
+                index,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "expect size {} at dimension {} ({}), but found {}",
+                expected, name, index, actual
+            ),
+            ShapeError::LengthMismatch { expected, actual } => {
+                write!(f, "expect {} elements, but found {}", expected, actual)
+            }
+            ShapeError::DynCountMismatch { expected, actual } => {
+                write!(f, "expect {} dynamic sizes, but found {}", expected, actual)
+            }
+            ShapeError::NegativeSize {
+                name,
+                index,
+                actual,
+            } => write!(
+                f,
+                "expect non-negative size at dimension {} ({}), but found {}",
+                name, index, actual
+            ),
+            ShapeError::KindMismatch { expected, actual } => {
+                write!(f, "expect kind {:?}, but found {:?}", expected, actual)
+            }
+            ShapeError::DeviceMismatch { expected, actual } => {
+                write!(f, "expect device {:?}, but found {:?}", expected, actual)
+            }
+        }
+    }
+}
+
+impl Error for ShapeError {}
@@ -23,6 +23,7 @@ macro_rules! define_kind {
     };
 }
 
+define_kind!(Bool, Bool, bool);
 define_kind!(Uint8, Uint8, u8);
 define_kind!(Int8, Int8, i8);
 define_kind!(Int16, Int16, i16);
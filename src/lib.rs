@@ -2,8 +2,11 @@
 
 pub extern crate typenum;
 
+pub mod array;
 pub mod device;
 pub mod dim;
+pub mod error;
 pub mod index;
 pub mod kind;
+pub mod nn;
 pub mod tensor;
@@ -0,0 +1,245 @@
+use super::{check_path_device, param_from_tch, Module};
+use crate::{
+    device::TensorDevice,
+    dim::{
+        DConvSize, DConvSizeOutput, Dim, DimSize, InChannel, KernelHeight, KernelWidth, OutChannel,
+    },
+    error::ShapeError,
+    kind::{FloatKind, TensorKind},
+    tensor::NamedTensor,
+    DimListType,
+};
+use std::{borrow::Borrow, marker::PhantomData};
+use tch::nn::{self, ConvConfig, ConvConfigND, Path};
+use typenum::{NonZero, Unsigned};
+
+// dimensions of convolution weights
+
+pub type Conv2dWeightDims<InSize, OutSize, KernelH, KernelW> = DimListType! {
+    (OutChannel, OutSize),
+    (InChannel, InSize),
+    (KernelHeight, KernelH),
+    (KernelWidth, KernelW)
+};
+
+// 2D convolution layer
+
+/// A 2D convolution layer that maps `InSize` channels to `OutSize` channels
+/// with a kernel of `KernelH` by `KernelW`. The stride, padding and dilation
+/// are applied equally on height and width.
+pub struct Conv2d<InSize, OutSize, KernelH, KernelW, Stride, Padding, Dilation, Kind, Dev>
+where
+    InSize: Unsigned,
+    OutSize: Unsigned,
+    KernelH: Unsigned + NonZero,
+    KernelW: Unsigned + NonZero,
+    Stride: Unsigned + NonZero,
+    Padding: Unsigned,
+    Dilation: Unsigned + NonZero,
+    Kind: TensorKind + FloatKind,
+    Dev: TensorDevice,
+{
+    weight: NamedTensor<Conv2dWeightDims<InSize, OutSize, KernelH, KernelW>, Kind, Dev>,
+    bias: Option<NamedTensor<DimListType! {(OutChannel, OutSize)}, Kind, Dev>>,
+    _phantom: PhantomData<(Stride, Padding, Dilation)>,
+}
+
+impl<InSize, OutSize, KernelH, KernelW, Stride, Padding, Dilation, Kind, Dev>
+    Conv2d<InSize, OutSize, KernelH, KernelW, Stride, Padding, Dilation, Kind, Dev>
+where
+    InSize: Unsigned,
+    OutSize: Unsigned,
+    KernelH: Unsigned + NonZero,
+    KernelW: Unsigned + NonZero,
+    Stride: Unsigned + NonZero,
+    Padding: Unsigned,
+    Dilation: Unsigned + NonZero,
+    Kind: TensorKind + FloatKind,
+    Dev: TensorDevice,
+{
+    /// Creates the layer with variables in `path`. The stride, padding,
+    /// dilation and groups in `config` are replaced by the type parameters.
+    /// It fails if the device of `path` is not `Dev`.
+    pub fn new<'a, P>(path: P, config: ConvConfig) -> Result<Self, ShapeError>
+    where
+        P: Borrow<Path<'a>>,
+    {
+        let path = path.borrow();
+        check_path_device::<Dev>(path)?;
+        let config = ConvConfigND {
+            stride: [Stride::I64, Stride::I64],
+            padding: [Padding::I64, Padding::I64],
+            dilation: [Dilation::I64, Dilation::I64],
+            groups: 1,
+            bias: config.bias,
+            ws_init: config.ws_init,
+            bs_init: config.bs_init,
+        };
+        let conv = nn::conv(
+            path,
+            InSize::I64,
+            OutSize::I64,
+            [KernelH::I64, KernelW::I64],
+            config,
+        );
+        let weight = param_from_tch(conv.ws)?;
+        let bias = conv.bs.map(param_from_tch).transpose()?;
+        Ok(Self {
+            weight,
+            bias,
+            _phantom: PhantomData,
+        })
+    }
+
+    pub fn weight(
+        &self,
+    ) -> &NamedTensor<Conv2dWeightDims<InSize, OutSize, KernelH, KernelW>, Kind, Dev> {
+        &self.weight
+    }
+
+    pub fn bias(&self) -> Option<&NamedTensor<DimListType! {(OutChannel, OutSize)}, Kind, Dev>> {
+        self.bias.as_ref()
+    }
+}
+
+impl<
+        InSize,
+        OutSize,
+        KernelH,
+        KernelW,
+        Stride,
+        Padding,
+        Dilation,
+        Kind,
+        Dev,
+        BatchDim,
+        BatchSize,
+        ChannelDim,
+        HeightDim,
+        HeightSize,
+        WidthDim,
+        WidthSize,
+    >
+    Module<
+        NamedTensor<
+            DimListType! {
+                (BatchDim, BatchSize),
+                (ChannelDim, InSize),
+                (HeightDim, HeightSize),
+                (WidthDim, WidthSize)
+            },
+            Kind,
+            Dev,
+        >,
+    > for Conv2d<InSize, OutSize, KernelH, KernelW, Stride, Padding, Dilation, Kind, Dev>
+where
+    InSize: Unsigned,
+    OutSize: Unsigned,
+    KernelH: Unsigned + NonZero,
+    KernelW: Unsigned + NonZero,
+    Stride: Unsigned + NonZero,
+    Padding: Unsigned,
+    Dilation: Unsigned + NonZero,
+    Kind: TensorKind + FloatKind,
+    Dev: TensorDevice,
+    BatchDim: Dim,
+    BatchSize: DimSize,
+    ChannelDim: Dim,
+    HeightDim: Dim,
+    HeightSize: DConvSize<KernelH, Stride, Padding, Dilation>,
+    WidthDim: Dim,
+    WidthSize: DConvSize<KernelW, Stride, Padding, Dilation>,
+{
+    type Output = NamedTensor<
+        DimListType! {
+            (BatchDim, BatchSize),
+            (ChannelDim, OutSize),
+            (HeightDim, DConvSizeOutput<HeightSize, KernelH, Stride, Padding, Dilation>),
+            (WidthDim, DConvSizeOutput<WidthSize, KernelW, Stride, Padding, Dilation>)
+        },
+        Kind,
+        Dev,
+    >;
+
+    fn forward(
+        &self,
+        input: &NamedTensor<
+            DimListType! {
+                (BatchDim, BatchSize),
+                (ChannelDim, InSize),
+                (HeightDim, HeightSize),
+                (WidthDim, WidthSize)
+            },
+            Kind,
+            Dev,
+        >,
+    ) -> Self::Output {
+        let output = input.tensor.conv2d(
+            &self.weight.tensor,
+            self.bias.as_ref().map(|bias| &bias.tensor),
+            &[Stride::I64, Stride::I64],
+            &[Padding::I64, Padding::I64],
+            &[Dilation::I64, Dilation::I64],
+            1,
+        );
+        NamedTensor::from_tch_tensor(output)
+    }
+}
+
+// tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{device::Cpu, dim::Dyn, kind::Float, make_dims};
+    use tch::{nn::VarStore, Device};
+    use typenum::consts::*;
+
+    make_dims! {Batch, Channel, Height, Width}
+
+    #[test]
+    fn conv2d_test() {
+        let vs = VarStore::new(Device::Cpu);
+        let conv = Conv2d::<U3, U8, U3, U5, U2, U1, U1, Float, Cpu>::new(
+            &vs.root() / "conv",
+            Default::default(),
+        )
+        .unwrap();
+        assert_eq!(vs.trainable_variables().len(), 2);
+        assert_eq!(conv.weight().size(), vec![8, 3, 3, 5]);
+
+        let input = NamedTensor::<
+            DimListType! {(Batch, U2), (Channel, U3), (Height, U32), (Width, U28)},
+            Float,
+            Cpu,
+        >::zeros();
+        let output: NamedTensor<
+            DimListType! {(Batch, U2), (Channel, U8), (Height, U16), (Width, U13)},
+            Float,
+            Cpu,
+        > = conv.forward(&input);
+        assert_eq!(output.size(), vec![2, 8, 16, 13]);
+
+        // the batch size can be known only in runtime
+        let input = NamedTensor::<
+            DimListType! {(Batch, Dyn), (Channel, U3), (Height, U32), (Width, U28)},
+            Float,
+            Cpu,
+        >::zeros_dyn(&[5])
+        .unwrap();
+        let output: NamedTensor<
+            DimListType! {(Batch, Dyn), (Channel, U8), (Height, U16), (Width, U13)},
+            Float,
+            Cpu,
+        > = conv.forward(&input);
+        assert_eq!(output.size(), vec![5, 8, 16, 13]);
+
+        // It triggers compile error because the input channel size is not 3
+        // let input = NamedTensor::<
+        //     DimListType! {(Batch, U2), (Channel, U4), (Height, U32), (Width, U28)},
+        //     Float,
+        //     Cpu,
+        // >::zeros();
+        // let _ = conv.forward(&input);
+    }
+}
@@ -0,0 +1,118 @@
+use super::{check_path_device, param_from_tch, Module};
+use crate::{
+    device::TensorDevice,
+    dim::{DReplaceLast, DReplaceLastOutput, Dim},
+    error::ShapeError,
+    kind::{FloatKind, TensorKind},
+    tensor::NamedTensor,
+    DimListType,
+};
+use std::borrow::Borrow;
+use tch::nn::{self, LinearConfig, Path};
+use typenum::Unsigned;
+
+// linear layer
+
+/// A fully-connected layer that maps the last dimension `(InDim, InSize)`
+/// to `(OutDim, OutSize)`.
+pub struct Linear<InDim, InSize, OutDim, OutSize, Kind, Dev>
+where
+    InDim: Dim,
+    InSize: Unsigned,
+    OutDim: Dim,
+    OutSize: Unsigned,
+    Kind: TensorKind + FloatKind,
+    Dev: TensorDevice,
+{
+    weight: NamedTensor<DimListType! {(OutDim, OutSize), (InDim, InSize)}, Kind, Dev>,
+    bias: Option<NamedTensor<DimListType! {(OutDim, OutSize)}, Kind, Dev>>,
+}
+
+impl<InDim, InSize, OutDim, OutSize, Kind, Dev> Linear<InDim, InSize, OutDim, OutSize, Kind, Dev>
+where
+    InDim: Dim,
+    InSize: Unsigned,
+    OutDim: Dim,
+    OutSize: Unsigned,
+    Kind: TensorKind + FloatKind,
+    Dev: TensorDevice,
+{
+    /// Creates the layer with variables in `path`. It fails if the device
+    /// of `path` is not `Dev`.
+    pub fn new<'a, P>(path: P, config: LinearConfig) -> Result<Self, ShapeError>
+    where
+        P: Borrow<Path<'a>>,
+    {
+        let path = path.borrow();
+        check_path_device::<Dev>(path)?;
+        let linear = nn::linear(path, InSize::I64, OutSize::I64, config);
+        let weight = param_from_tch(linear.ws)?;
+        let bias = linear.bs.map(param_from_tch).transpose()?;
+        Ok(Self { weight, bias })
+    }
+
+    pub fn weight(
+        &self,
+    ) -> &NamedTensor<DimListType! {(OutDim, OutSize), (InDim, InSize)}, Kind, Dev> {
+        &self.weight
+    }
+
+    pub fn bias(&self) -> Option<&NamedTensor<DimListType! {(OutDim, OutSize)}, Kind, Dev>> {
+        self.bias.as_ref()
+    }
+}
+
+impl<InDim, InSize, OutDim, OutSize, Kind, Dev, Dims> Module<NamedTensor<Dims, Kind, Dev>>
+    for Linear<InDim, InSize, OutDim, OutSize, Kind, Dev>
+where
+    InDim: Dim,
+    InSize: Unsigned,
+    OutDim: Dim,
+    OutSize: Unsigned,
+    Kind: TensorKind + FloatKind,
+    Dev: TensorDevice,
+    Dims: DReplaceLast<InDim, InSize, OutDim, OutSize>,
+{
+    type Output = NamedTensor<DReplaceLastOutput<Dims, InDim, InSize, OutDim, OutSize>, Kind, Dev>;
+
+    fn forward(&self, input: &NamedTensor<Dims, Kind, Dev>) -> Self::Output {
+        let output = input.tensor.matmul(&self.weight.tensor.tr());
+        let output = match &self.bias {
+            Some(bias) => output + &bias.tensor,
+            None => output,
+        };
+        NamedTensor::from_tch_tensor(output)
+    }
+}
+
+// tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{device::Cpu, kind::Float, make_dims};
+    use tch::{nn::VarStore, Device};
+    use typenum::consts::*;
+
+    make_dims! {Batch, Feature, Hidden}
+
+    #[test]
+    fn linear_test() {
+        let vs = VarStore::new(Device::Cpu);
+        let linear = Linear::<Feature, U4, Hidden, U3, Float, Cpu>::new(
+            &vs.root() / "linear",
+            Default::default(),
+        )
+        .unwrap();
+        assert_eq!(vs.trainable_variables().len(), 2);
+        assert_eq!(linear.weight().size(), vec![3, 4]);
+
+        let input = NamedTensor::<DimListType! {(Batch, U2), (Feature, U4)}, Float, Cpu>::zeros();
+        let _: NamedTensor<DimListType! {(Batch, U2), (Hidden, U3)}, Float, Cpu> =
+            linear.forward(&input);
+
+        // It triggers compile error because the feature size is not 4
+        // let input = NamedTensor::<DimListType! {(Batch, U2), (Feature, U5)}, Float, Cpu>::zeros();
+        // let _ = linear.forward(&input);
+    }
+}
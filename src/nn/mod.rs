@@ -0,0 +1,67 @@
+mod conv;
+mod linear;
+mod norm;
+mod sequential;
+
+pub use conv::*;
+pub use linear::*;
+pub use norm::*;
+pub use sequential::*;
+
+use crate::{
+    device::TensorDevice, dim::DimList, error::ShapeError, kind::TensorKind, tensor::NamedTensor,
+};
+use tch::{nn::Path, Tensor};
+
+// module
+
+/// A layer that maps `Input` tensors to outputs, which types are checked
+/// in compile time.
+///
+/// The `Index` is inferred automatically. It locates the dimensions in
+/// `Input` for layers that refer to dimensions by name, and is `()` for
+/// the others.
+pub trait Module<Input, Index = ()> {
+    type Output;
+
+    fn forward(&self, input: &Input) -> Self::Output;
+}
+
+// parameters
+
+/// Checks that a [Path] is on `Dev` device. Layers call it before creating
+/// variables, so that no variable is left in the [VarStore](tch::nn::VarStore)
+/// on failure.
+fn check_path_device<Dev>(path: &Path) -> Result<(), ShapeError>
+where
+    Dev: TensorDevice,
+{
+    let actual = path.device();
+    if actual != Dev::DEVICE {
+        return Err(ShapeError::DeviceMismatch {
+            expected: Dev::DEVICE,
+            actual,
+        });
+    }
+    Ok(())
+}
+
+/// Wraps a variable created by a [Path] as a typed parameter.
+/// The variable is cast to `Kind` in place, so that it is still tracked
+/// by the [VarStore](tch::nn::VarStore). The device must have been checked
+/// by [check_path_device] before the variable is created.
+fn param_from_tch<Dims, Kind, Dev>(
+    mut tensor: Tensor,
+) -> Result<NamedTensor<Dims, Kind, Dev>, ShapeError>
+where
+    Dims: DimList,
+    Kind: TensorKind,
+    Dev: TensorDevice,
+{
+    debug_assert_eq!(tensor.device(), Dev::DEVICE);
+    if tensor.kind() != Kind::KIND {
+        let converted = tensor.to_kind(Kind::KIND);
+        tensor.set_data(&converted);
+    }
+    NamedTensor::try_from_tch(tensor)
+}
@@ -0,0 +1,476 @@
+use super::{check_path_device, param_from_tch, Module};
+use crate::{
+    device::TensorDevice,
+    dim::{
+        DContainDims, DExtractDim, DExtractDimOutput, DIndexOf, DIndexOfIndex, DRemoveMany,
+        DRemoveManyOutput, DSizeProduct, DSizeProductOutput, Dim, StaticDimList,
+    },
+    error::ShapeError,
+    kind::{FloatKind, TensorKind},
+    tensor::NamedTensor,
+};
+use std::{borrow::Borrow, marker::PhantomData, ops::Rem};
+use tch::nn::{self, BatchNormConfig, Init, LayerNormConfig, Path};
+use type_freak::{counter::Counter, list::TList};
+use typenum::{NonZero, Unsigned, U0};
+
+// permutation of normalized dimensions
+
+/// Moves the dimensions at `indexes` to the front, keeping the order of the
+/// others.
+fn front_permute_index(len: usize, indexes: &[usize]) -> Vec<i64> {
+    indexes
+        .iter()
+        .cloned()
+        .chain((0..len).filter(|index| !indexes.contains(index)))
+        .map(|index| index as i64)
+        .collect()
+}
+
+/// Moves the dimensions at `indexes` to the back, keeping the order of the
+/// others.
+fn back_permute_index(len: usize, indexes: &[usize]) -> Vec<i64> {
+    (0..len)
+        .filter(|index| !indexes.contains(index))
+        .chain(indexes.iter().cloned())
+        .map(|index| index as i64)
+        .collect()
+}
+
+fn reverse_permute_index(permute_index: &[i64]) -> Vec<i64> {
+    let mut indexes = vec![0; permute_index.len()];
+    for (from, &to) in permute_index.iter().enumerate() {
+        indexes[to as usize] = from as i64;
+    }
+    indexes
+}
+
+// layer normalization
+
+/// A layer normalization over the `NormDims` dimensions, which sizes must
+/// be static.
+///
+/// It applies to any input that contains every dimension of `NormDims` with
+/// the same size, in any order and not necessarily trailing.
+pub struct LayerNorm<NormDims, Kind, Dev>
+where
+    NormDims: StaticDimList,
+    Kind: TensorKind + FloatKind,
+    Dev: TensorDevice,
+{
+    weight: Option<NamedTensor<NormDims, Kind, Dev>>,
+    bias: Option<NamedTensor<NormDims, Kind, Dev>>,
+    eps: f64,
+    cudnn_enabled: bool,
+}
+
+impl<NormDims, Kind, Dev> LayerNorm<NormDims, Kind, Dev>
+where
+    NormDims: StaticDimList,
+    Kind: TensorKind + FloatKind,
+    Dev: TensorDevice,
+{
+    /// Creates the layer with variables in `path`. It fails if the device
+    /// of `path` is not `Dev`.
+    pub fn new<'a, P>(path: P, config: LayerNormConfig) -> Result<Self, ShapeError>
+    where
+        P: Borrow<Path<'a>>,
+    {
+        let path = path.borrow();
+        check_path_device::<Dev>(path)?;
+        let layer_norm = nn::layer_norm(path, NormDims::shape_i64(), config);
+        let weight = layer_norm.ws.map(param_from_tch).transpose()?;
+        let bias = layer_norm.bs.map(param_from_tch).transpose()?;
+        Ok(Self {
+            weight,
+            bias,
+            eps: config.eps,
+            cudnn_enabled: config.cudnn_enabled,
+        })
+    }
+
+    pub fn weight(&self) -> Option<&NamedTensor<NormDims, Kind, Dev>> {
+        self.weight.as_ref()
+    }
+
+    pub fn bias(&self) -> Option<&NamedTensor<NormDims, Kind, Dev>> {
+        self.bias.as_ref()
+    }
+}
+
+impl<NormDims, Kind, Dev, Dims, Indexes> Module<NamedTensor<Dims, Kind, Dev>, Indexes>
+    for LayerNorm<NormDims, Kind, Dev>
+where
+    NormDims: StaticDimList,
+    Kind: TensorKind + FloatKind,
+    Dev: TensorDevice,
+    Dims: DContainDims<NormDims, Indexes>,
+    Indexes: TList,
+{
+    type Output = NamedTensor<Dims, Kind, Dev>;
+
+    fn forward(&self, input: &NamedTensor<Dims, Kind, Dev>) -> Self::Output {
+        let indexes = <Dims as DContainDims<NormDims, Indexes>>::indexes();
+        let permute_index = back_permute_index(input.tensor.dim(), &indexes);
+        let output = input
+            .tensor
+            .permute(&permute_index)
+            .layer_norm(
+                &NormDims::shape_i64(),
+                self.weight.as_ref().map(|weight| &weight.tensor),
+                self.bias.as_ref().map(|bias| &bias.tensor),
+                self.eps,
+                self.cudnn_enabled,
+            )
+            .permute(&reverse_permute_index(&permute_index));
+        NamedTensor::from_tch_tensor(output)
+    }
+}
+
+// batch normalization
+
+/// A batch normalization that keeps statistics for each position of the
+/// `NormDims` dimensions, computed over the other dimensions of the input.
+pub struct BatchNorm<NormDims, Kind, Dev>
+where
+    NormDims: StaticDimList + DSizeProduct,
+    Kind: TensorKind + FloatKind,
+    Dev: TensorDevice,
+{
+    weight: NamedTensor<NormDims, Kind, Dev>,
+    bias: NamedTensor<NormDims, Kind, Dev>,
+    running_mean: NamedTensor<NormDims, Kind, Dev>,
+    running_var: NamedTensor<NormDims, Kind, Dev>,
+    config: BatchNormConfig,
+}
+
+impl<NormDims, Kind, Dev> BatchNorm<NormDims, Kind, Dev>
+where
+    NormDims: StaticDimList + DSizeProduct,
+    Kind: TensorKind + FloatKind,
+    Dev: TensorDevice,
+{
+    /// Creates the layer with variables in `path`. It fails if the device
+    /// of `path` is not `Dev`.
+    pub fn new<'a, P>(path: P, config: BatchNormConfig) -> Result<Self, ShapeError>
+    where
+        P: Borrow<Path<'a>>,
+    {
+        let path = path.borrow();
+        check_path_device::<Dev>(path)?;
+        let shape = NormDims::shape_i64();
+        Ok(Self {
+            weight: param_from_tch(path.var("weight", &shape, config.ws_init))?,
+            bias: param_from_tch(path.var("bias", &shape, config.bs_init))?,
+            running_mean: param_from_tch(path.zeros_no_train("running_mean", &shape))?,
+            running_var: param_from_tch(path.ones_no_train("running_var", &shape))?,
+            config,
+        })
+    }
+
+    pub fn weight(&self) -> &NamedTensor<NormDims, Kind, Dev> {
+        &self.weight
+    }
+
+    pub fn bias(&self) -> &NamedTensor<NormDims, Kind, Dev> {
+        &self.bias
+    }
+
+    /// Normalizes `input` with batch statistics when `train` is true,
+    /// updating the running statistics, or with the running statistics
+    /// otherwise.
+    pub fn forward_t<Dims, Indexes>(
+        &self,
+        input: &NamedTensor<Dims, Kind, Dev>,
+        train: bool,
+    ) -> NamedTensor<Dims, Kind, Dev>
+    where
+        Dims: DContainDims<NormDims, Indexes>,
+        Indexes: TList,
+    {
+        let indexes = <Dims as DContainDims<NormDims, Indexes>>::indexes();
+        let permute_index = front_permute_index(input.tensor.dim(), &indexes);
+        let permuted = input.tensor.permute(&permute_index);
+        let shape = permuted.size();
+        let output = permuted
+            .reshape(&[1, DSizeProductOutput::<NormDims>::I64, -1])
+            .batch_norm(
+                Some(self.weight.tensor.view(-1i64)),
+                Some(self.bias.tensor.view(-1i64)),
+                Some(self.running_mean.tensor.view(-1i64)),
+                Some(self.running_var.tensor.view(-1i64)),
+                train,
+                self.config.momentum,
+                self.config.eps,
+                self.config.cudnn_enabled,
+            )
+            .reshape(&shape)
+            .permute(&reverse_permute_index(&permute_index));
+        NamedTensor::from_tch_tensor(output)
+    }
+}
+
+impl<NormDims, Kind, Dev, Dims, Indexes> Module<NamedTensor<Dims, Kind, Dev>, Indexes>
+    for BatchNorm<NormDims, Kind, Dev>
+where
+    NormDims: StaticDimList + DSizeProduct,
+    Kind: TensorKind + FloatKind,
+    Dev: TensorDevice,
+    Dims: DContainDims<NormDims, Indexes>,
+    Indexes: TList,
+{
+    type Output = NamedTensor<Dims, Kind, Dev>;
+
+    /// Normalizes `input` with the running statistics.
+    fn forward(&self, input: &NamedTensor<Dims, Kind, Dev>) -> Self::Output {
+        self.forward_t::<Dims, Indexes>(input, false)
+    }
+}
+
+// group normalization
+
+/// Group normalization config.
+#[derive(Debug, Clone, Copy)]
+pub struct GroupNormConfig {
+    pub cudnn_enabled: bool,
+    pub eps: f64,
+    pub ws_init: Init,
+    pub bs_init: Init,
+}
+
+impl Default for GroupNormConfig {
+    fn default() -> Self {
+        GroupNormConfig {
+            cudnn_enabled: true,
+            eps: 1e-5,
+            ws_init: Init::Const(1.),
+            bs_init: Init::Const(0.),
+        }
+    }
+}
+
+/// A group normalization that splits the channels formed by the `NormDims`
+/// dimensions into `Groups` groups. The statistics are computed for each
+/// group and each position of `Batch`, over the other dimensions of the input.
+///
+/// The number of channels must be divisible by `Groups`, and `Batch` must
+/// not be one of `NormDims`. Both are checked in compile time.
+pub struct GroupNorm<Groups, Batch, NormDims, Kind, Dev>
+where
+    Groups: Unsigned + NonZero,
+    Batch: Dim,
+    NormDims: StaticDimList + DSizeProduct,
+    DSizeProductOutput<NormDims>: Rem<Groups, Output = U0>,
+    Kind: TensorKind + FloatKind,
+    Dev: TensorDevice,
+{
+    weight: NamedTensor<NormDims, Kind, Dev>,
+    bias: NamedTensor<NormDims, Kind, Dev>,
+    config: GroupNormConfig,
+    _phantom: PhantomData<(Groups, Batch)>,
+}
+
+impl<Groups, Batch, NormDims, Kind, Dev> GroupNorm<Groups, Batch, NormDims, Kind, Dev>
+where
+    Groups: Unsigned + NonZero,
+    Batch: Dim,
+    NormDims: StaticDimList + DSizeProduct,
+    DSizeProductOutput<NormDims>: Rem<Groups, Output = U0>,
+    Kind: TensorKind + FloatKind,
+    Dev: TensorDevice,
+{
+    /// Creates the layer with variables in `path`. It fails if the device
+    /// of `path` is not `Dev`.
+    pub fn new<'a, P>(path: P, config: GroupNormConfig) -> Result<Self, ShapeError>
+    where
+        P: Borrow<Path<'a>>,
+    {
+        let path = path.borrow();
+        check_path_device::<Dev>(path)?;
+        let shape = NormDims::shape_i64();
+        Ok(Self {
+            weight: param_from_tch(path.var("weight", &shape, config.ws_init))?,
+            bias: param_from_tch(path.var("bias", &shape, config.bs_init))?,
+            config,
+            _phantom: PhantomData,
+        })
+    }
+
+    pub fn weight(&self) -> &NamedTensor<NormDims, Kind, Dev> {
+        &self.weight
+    }
+
+    pub fn bias(&self) -> &NamedTensor<NormDims, Kind, Dev> {
+        &self.bias
+    }
+}
+
+impl<Groups, Batch, NormDims, Kind, Dev, Dims, BatchIndex, Indexes>
+    Module<NamedTensor<Dims, Kind, Dev>, (BatchIndex, Indexes)>
+    for GroupNorm<Groups, Batch, NormDims, Kind, Dev>
+where
+    Groups: Unsigned + NonZero,
+    Batch: Dim,
+    NormDims: StaticDimList + DSizeProduct,
+    DSizeProductOutput<NormDims>: Rem<Groups, Output = U0>,
+    Kind: TensorKind + FloatKind,
+    Dev: TensorDevice,
+    NormDims: DExtractDim,
+    Dims: DContainDims<NormDims, Indexes> + DRemoveMany<DExtractDimOutput<NormDims>, Indexes>,
+    DRemoveManyOutput<Dims, DExtractDimOutput<NormDims>, Indexes>: DIndexOf<Batch, BatchIndex>,
+    BatchIndex: Counter,
+    Indexes: TList,
+{
+    type Output = NamedTensor<Dims, Kind, Dev>;
+
+    fn forward(&self, input: &NamedTensor<Dims, Kind, Dev>) -> Self::Output {
+        let mut indexes = <Dims as DContainDims<NormDims, Indexes>>::indexes();
+
+        // Batch is located among the dimensions other than NormDims
+        let rest_index = DIndexOfIndex::<
+            DRemoveManyOutput<Dims, DExtractDimOutput<NormDims>, Indexes>,
+            Batch,
+            BatchIndex,
+        >::USIZE;
+        let batch_index = (0..input.tensor.dim())
+            .filter(|index| !indexes.contains(index))
+            .nth(rest_index)
+            .unwrap();
+        indexes.insert(0, batch_index);
+        let permute_index = front_permute_index(input.tensor.dim(), &indexes);
+        let permuted = input.tensor.permute(&permute_index);
+        let shape = permuted.size();
+        let output = permuted
+            .reshape(&[shape[0], DSizeProductOutput::<NormDims>::I64, -1])
+            .group_norm(
+                Groups::I64,
+                Some(self.weight.tensor.view(-1i64)),
+                Some(self.bias.tensor.view(-1i64)),
+                self.config.eps,
+                self.config.cudnn_enabled,
+            )
+            .reshape(&shape)
+            .permute(&reverse_permute_index(&permute_index));
+        NamedTensor::from_tch_tensor(output)
+    }
+}
+
+// tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{device::Cpu, kind::Float, make_dims, DimListType};
+    use tch::{nn::VarStore, Device, Kind as TchKind, Tensor};
+    use typenum::consts::*;
+
+    make_dims! {Batch, Seq, Feature}
+
+    type InputDims = DimListType! {(Batch, U2), (Seq, U3), (Feature, U4)};
+    type InputTensor = NamedTensor<InputDims, Float, Cpu>;
+
+    fn max_abs_mean(tensor: &Tensor, dims: &[i64]) -> f64 {
+        tensor
+            .mean_dim(dims, false, TchKind::Float)
+            .abs()
+            .max()
+            .double_value(&[])
+    }
+
+    #[test]
+    fn norm_test() {
+        let vs = VarStore::new(Device::Cpu);
+        let input = InputTensor::randn();
+
+        // layer norm over a non-trailing dimension
+        let layer_norm = LayerNorm::<DimListType! {(Seq, U3)}, Float, Cpu>::new(
+            &vs.root() / "layer_norm",
+            Default::default(),
+        )
+        .unwrap();
+        assert_eq!(layer_norm.weight().unwrap().size(), vec![3]);
+        let output: InputTensor = layer_norm.forward(&input);
+        assert!(max_abs_mean(&output.tensor, &[1]) < 1e-4);
+
+        // the layer applies to inputs of other batch sizes
+        let single =
+            NamedTensor::<DimListType! {(Batch, U1), (Seq, U3), (Feature, U4)}, Float, Cpu>::randn(
+            );
+        let _ = layer_norm.forward(&single);
+
+        // batch norm keeps statistics per feature
+        let batch_norm = BatchNorm::<DimListType! {(Feature, U4)}, Float, Cpu>::new(
+            &vs.root() / "batch_norm",
+            Default::default(),
+        )
+        .unwrap();
+        assert_eq!(batch_norm.weight().size(), vec![4]);
+        let output = batch_norm.forward_t(&input, true);
+        assert!(max_abs_mean(&output.tensor, &[0, 1]) < 1e-4);
+
+        // training updates the running mean with the default momentum 0.1
+        let batch_mean = input.tensor.mean_dim(&[0, 1], false, TchKind::Float);
+        let running_mean = &batch_norm.running_mean.tensor;
+        assert!(running_mean.allclose(&(&batch_mean * 0.1), 1e-5, 1e-6, false));
+
+        // evaluation normalizes with the running statistics
+        let output: InputTensor = batch_norm.forward(&input);
+        let expect =
+            (&input.tensor - running_mean) / (&batch_norm.running_var.tensor + 1e-5).sqrt();
+        assert!(output.tensor.allclose(&expect, 1e-4, 1e-5, false));
+        let _ = batch_norm.forward(&single);
+
+        // group norm splits 4 features into 2 groups
+        let group_norm = GroupNorm::<U2, Batch, DimListType! {(Feature, U4)}, Float, Cpu>::new(
+            &vs.root() / "group_norm",
+            Default::default(),
+        )
+        .unwrap();
+        let output: InputTensor = group_norm.forward(&input);
+        assert_eq!(output.size(), vec![2, 3, 4]);
+
+        // each (batch, group) slice has zero mean and unit variance
+        let grouped = output.tensor.view([2, 3, 2, 2]);
+        assert!(max_abs_mean(&grouped, &[1, 3]) < 1e-4);
+        let variance = (&grouped * &grouped).mean_dim(&[1, 3], false, TchKind::Float);
+        assert!((variance - 1.0).abs().max().double_value(&[]) < 1e-3);
+
+        // It triggers compile error because Batch is normalized as well
+        // let group_norm = GroupNorm::<
+        //     U2,
+        //     Batch,
+        //     DimListType! {(Batch, U2), (Feature, U4)},
+        //     Float,
+        //     Cpu,
+        // >::new(&vs.root() / "group_norm", Default::default())
+        // .unwrap();
+        // let _ = group_norm.forward(&input);
+
+        // It triggers compile error because 4 features cannot be split into 3 groups
+        // let _ = GroupNorm::<U3, Batch, DimListType! {(Feature, U4)}, Float, Cpu>::new(
+        //     &vs.root() / "group_norm",
+        //     Default::default(),
+        // );
+
+        // It triggers compile error because the normalized size is not static
+        // let _ = LayerNorm::<DimListType! {(Seq, Dyn)}, Float, Cpu>::new(
+        //     &vs.root() / "layer_norm",
+        //     Default::default(),
+        // );
+
+        // It triggers compile error because the normalized size differs from the input
+        // let layer_norm = LayerNorm::<DimListType! {(Seq, U5)}, Float, Cpu>::new(
+        //     &vs.root() / "layer_norm",
+        //     Default::default(),
+        // )
+        // .unwrap();
+        // let _ = layer_norm.forward(&input);
+    }
+
+    #[test]
+    fn permute_index_test() {
+        assert_eq!(front_permute_index(4, &[2, 0]), vec![2, 0, 1, 3]);
+        assert_eq!(back_permute_index(4, &[2, 0]), vec![1, 3, 2, 0]);
+        assert_eq!(reverse_permute_index(&[2, 0, 1, 3]), vec![1, 2, 0, 3]);
+    }
+}
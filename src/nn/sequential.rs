@@ -0,0 +1,152 @@
+use super::Module;
+
+// list of modules
+
+/// The end of a sequential list of modules.
+pub struct SeqNil;
+
+/// A node of a list of modules, which feeds the output of `head` to `tail`.
+pub struct SeqCons<Head, Tail> {
+    head: Head,
+    tail: Tail,
+}
+
+/// Starts an empty list of modules, to which layers are appended by `add`.
+pub fn seq() -> SeqNil {
+    SeqNil
+}
+
+impl SeqNil {
+    pub fn add<M>(self, module: M) -> SeqAppendOutput<Self, M> {
+        self.append(module)
+    }
+}
+
+impl<Head, Tail> SeqCons<Head, Tail> {
+    pub fn add<M>(self, module: M) -> SeqAppendOutput<Self, M>
+    where
+        Self: SeqAppend<M>,
+    {
+        self.append(module)
+    }
+
+    pub fn head(&self) -> &Head {
+        &self.head
+    }
+
+    pub fn tail(&self) -> &Tail {
+        &self.tail
+    }
+}
+
+// append a module
+
+/// A type operator that appends the module `M` to the end of the list.
+pub trait SeqAppend<M> {
+    type Output;
+
+    fn append(self, module: M) -> Self::Output;
+}
+
+pub type SeqAppendOutput<List, M> = <List as SeqAppend<M>>::Output;
+
+impl<M> SeqAppend<M> for SeqNil {
+    type Output = SeqCons<M, SeqNil>;
+
+    fn append(self, module: M) -> Self::Output {
+        SeqCons {
+            head: module,
+            tail: SeqNil,
+        }
+    }
+}
+
+impl<M, Head, Tail> SeqAppend<M> for SeqCons<Head, Tail>
+where
+    Tail: SeqAppend<M>,
+{
+    type Output = SeqCons<Head, SeqAppendOutput<Tail, M>>;
+
+    fn append(self, module: M) -> Self::Output {
+        SeqCons {
+            head: self.head,
+            tail: self.tail.append(module),
+        }
+    }
+}
+
+// forward through modules
+
+impl<Input, Index, Head> Module<Input, Index> for SeqCons<Head, SeqNil>
+where
+    Head: Module<Input, Index>,
+{
+    type Output = Head::Output;
+
+    fn forward(&self, input: &Input) -> Self::Output {
+        self.head.forward(input)
+    }
+}
+
+impl<Input, HeadIndex, TailIndex, Head, Next, Tail> Module<Input, (HeadIndex, TailIndex)>
+    for SeqCons<Head, SeqCons<Next, Tail>>
+where
+    Head: Module<Input, HeadIndex>,
+    SeqCons<Next, Tail>: Module<Head::Output, TailIndex>,
+{
+    type Output = <SeqCons<Next, Tail> as Module<Head::Output, TailIndex>>::Output;
+
+    fn forward(&self, input: &Input) -> Self::Output {
+        self.tail.forward(&self.head.forward(input))
+    }
+}
+
+// tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        device::Cpu, kind::Float, make_dims, nn::Linear, tensor::NamedTensor, DimListType,
+    };
+    use tch::{nn::VarStore, Device};
+    use typenum::consts::*;
+
+    make_dims! {Batch, Feature, Hidden, Class}
+
+    #[test]
+    fn sequential_test() {
+        let vs = VarStore::new(Device::Cpu);
+        let root = vs.root();
+        let model = seq()
+            .add(
+                Linear::<Feature, U4, Hidden, U3, Float, Cpu>::new(
+                    &root / "fc1",
+                    Default::default(),
+                )
+                .unwrap(),
+            )
+            .add(
+                Linear::<Hidden, U3, Class, U2, Float, Cpu>::new(&root / "fc2", Default::default())
+                    .unwrap(),
+            );
+        assert_eq!(vs.trainable_variables().len(), 4);
+
+        let input = NamedTensor::<DimListType! {(Batch, U5), (Feature, U4)}, Float, Cpu>::zeros();
+        let output: NamedTensor<DimListType! {(Batch, U5), (Class, U2)}, Float, Cpu> =
+            model.forward(&input);
+        assert_eq!(output.size(), vec![5, 2]);
+
+        // It triggers compile error because the layers do not fit in reversed order
+        // let model = seq()
+        //     .add(
+        //         Linear::<Hidden, U3, Class, U2, Float, Cpu>::new(&root / "fc3", Default::default())
+        //             .unwrap(),
+        //     )
+        //     .add(
+        //         Linear::<Feature, U4, Hidden, U3, Float, Cpu>::new(&root / "fc4", Default::default())
+        //             .unwrap(),
+        //     );
+        // let _ = model.forward(&input);
+    }
+}
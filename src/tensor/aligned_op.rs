@@ -0,0 +1,173 @@
+use super::NamedTensor;
+use crate::{
+    device::TensorDevice,
+    dim::{
+        AlignMatcher, BroadcastMatcher, DAlignTo, DAlignToOutput, DAlignedOutput, DBroadcastBoth,
+        DimList,
+    },
+    kind::TensorKind,
+};
+
+// binary op aligned by names
+
+/// Element-wise binary operations that match dimensions by names rather
+/// than positions. The right-hand-side tensor is aligned to the dimension
+/// order of left-hand-side by [DAlignTo], where missing names become size-one
+/// dimensions, and then broadcasted by [DBroadcastBoth].
+pub trait TensorAlignedOp<LDims, RDims, Kind, Dev>
+where
+    LDims: DimList,
+    RDims: DimList,
+    Kind: TensorKind,
+    Dev: TensorDevice,
+{
+    fn add_by_name<AlignM, BcastM>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DAlignedOutput<LDims, RDims, AlignM, BcastM>, Kind, Dev>
+    where
+        AlignM: AlignMatcher,
+        BcastM: BroadcastMatcher,
+        RDims: DAlignTo<LDims, AlignM>,
+        LDims: DBroadcastBoth<DAlignToOutput<RDims, LDims, AlignM>, BcastM>;
+
+    fn sub_by_name<AlignM, BcastM>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DAlignedOutput<LDims, RDims, AlignM, BcastM>, Kind, Dev>
+    where
+        AlignM: AlignMatcher,
+        BcastM: BroadcastMatcher,
+        RDims: DAlignTo<LDims, AlignM>,
+        LDims: DBroadcastBoth<DAlignToOutput<RDims, LDims, AlignM>, BcastM>;
+
+    fn mul_by_name<AlignM, BcastM>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DAlignedOutput<LDims, RDims, AlignM, BcastM>, Kind, Dev>
+    where
+        AlignM: AlignMatcher,
+        BcastM: BroadcastMatcher,
+        RDims: DAlignTo<LDims, AlignM>,
+        LDims: DBroadcastBoth<DAlignToOutput<RDims, LDims, AlignM>, BcastM>;
+
+    fn div_by_name<AlignM, BcastM>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DAlignedOutput<LDims, RDims, AlignM, BcastM>, Kind, Dev>
+    where
+        AlignM: AlignMatcher,
+        BcastM: BroadcastMatcher,
+        RDims: DAlignTo<LDims, AlignM>,
+        LDims: DBroadcastBoth<DAlignToOutput<RDims, LDims, AlignM>, BcastM>;
+}
+
+impl<LDims, RDims, Kind, Dev> TensorAlignedOp<LDims, RDims, Kind, Dev>
+    for NamedTensor<LDims, Kind, Dev>
+where
+    LDims: DimList,
+    RDims: DimList,
+    Kind: TensorKind,
+    Dev: TensorDevice,
+{
+    fn add_by_name<AlignM, BcastM>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DAlignedOutput<LDims, RDims, AlignM, BcastM>, Kind, Dev>
+    where
+        AlignM: AlignMatcher,
+        BcastM: BroadcastMatcher,
+        RDims: DAlignTo<LDims, AlignM>,
+        LDims: DBroadcastBoth<DAlignToOutput<RDims, LDims, AlignM>, BcastM>,
+    {
+        let rhs = rhs.align_to::<LDims, AlignM>();
+        NamedTensor::from_tch_tensor(&self.tensor + &rhs.tensor)
+    }
+
+    fn sub_by_name<AlignM, BcastM>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DAlignedOutput<LDims, RDims, AlignM, BcastM>, Kind, Dev>
+    where
+        AlignM: AlignMatcher,
+        BcastM: BroadcastMatcher,
+        RDims: DAlignTo<LDims, AlignM>,
+        LDims: DBroadcastBoth<DAlignToOutput<RDims, LDims, AlignM>, BcastM>,
+    {
+        let rhs = rhs.align_to::<LDims, AlignM>();
+        NamedTensor::from_tch_tensor(&self.tensor - &rhs.tensor)
+    }
+
+    fn mul_by_name<AlignM, BcastM>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DAlignedOutput<LDims, RDims, AlignM, BcastM>, Kind, Dev>
+    where
+        AlignM: AlignMatcher,
+        BcastM: BroadcastMatcher,
+        RDims: DAlignTo<LDims, AlignM>,
+        LDims: DBroadcastBoth<DAlignToOutput<RDims, LDims, AlignM>, BcastM>,
+    {
+        let rhs = rhs.align_to::<LDims, AlignM>();
+        NamedTensor::from_tch_tensor(&self.tensor * &rhs.tensor)
+    }
+
+    fn div_by_name<AlignM, BcastM>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DAlignedOutput<LDims, RDims, AlignM, BcastM>, Kind, Dev>
+    where
+        AlignM: AlignMatcher,
+        BcastM: BroadcastMatcher,
+        RDims: DAlignTo<LDims, AlignM>,
+        LDims: DBroadcastBoth<DAlignToOutput<RDims, LDims, AlignM>, BcastM>,
+    {
+        let rhs = rhs.align_to::<LDims, AlignM>();
+        NamedTensor::from_tch_tensor(&self.tensor / &rhs.tensor)
+    }
+}
+
+// tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{device::Cpu, kind::Double, make_dims, DimListType};
+    use typenum::consts::*;
+
+    make_dims! {Batch, Channel, Height, Width}
+
+    type ImageDims = DimListType! {(Batch, U2), (Channel, U3), (Height, U4), (Width, U5)};
+
+    #[test]
+    fn tensor_aligned_op_test() {
+        let images = NamedTensor::<ImageDims, Double, Cpu>::zeros();
+        let bias = NamedTensor::<DimListType! {(Channel, U3)}, Double, Cpu>::zeros();
+        let mask = NamedTensor::<DimListType! {(Width, U5), (Height, U4)}, Double, Cpu>::zeros();
+
+        let _: NamedTensor<ImageDims, Double, Cpu> = images.add_by_name(&bias);
+        let _: NamedTensor<ImageDims, Double, Cpu> = images.sub_by_name(&bias);
+        let _: NamedTensor<ImageDims, Double, Cpu> = images.mul_by_name(&mask);
+        let _: NamedTensor<ImageDims, Double, Cpu> = images.div_by_name(&images);
+
+        // It triggers compile error because Batch is missing on the left
+        // let _ = bias.add_by_name(&images);
+    }
+
+    #[test]
+    fn tensor_aligned_op_value_test() {
+        let images = NamedTensor::<ImageDims, Double, Cpu>::randn();
+
+        // the operand is permuted and unsqueezed to (1, Channel, Height, 1)
+        let other = NamedTensor::<DimListType! {(Height, U4), (Channel, U3)}, Double, Cpu>::randn();
+        let output: NamedTensor<ImageDims, Double, Cpu> = images.add_by_name(&other);
+        let expect = &images.tensor + other.tensor.permute(&[1, 0]).reshape(&[1, 3, 4, 1]);
+        assert!(output.tensor.allclose(&expect, 1e-9, 1e-12, false));
+
+        // the operand is permuted to (Height, Width)
+        let mask = NamedTensor::<DimListType! {(Width, U5), (Height, U4)}, Double, Cpu>::randn();
+        let output: NamedTensor<ImageDims, Double, Cpu> = images.mul_by_name(&mask);
+        let expect = &images.tensor * mask.tensor.permute(&[1, 0]);
+        assert!(output.tensor.allclose(&expect, 1e-9, 1e-12, false));
+    }
+}
@@ -0,0 +1,119 @@
+use super::{NamedTensor, NamedTensorTrait};
+use crate::{
+    device::TensorDevice,
+    dim::{DimList, ScalarDim},
+    kind::TensorKind,
+};
+
+// gradient tracking
+
+pub trait TensorAutograd
+where
+    Self: NamedTensorTrait + Sized,
+{
+    /// Returns a tensor sharing the same data, which gradient
+    /// tracking is set to `requires_grad`.
+    fn set_requires_grad(&self, requires_grad: bool) -> Self;
+
+    fn requires_grad(&self) -> bool;
+
+    /// Returns the accumulated gradient of the same typed shape, or
+    /// `None` if no gradient is computed yet.
+    fn grad(&self) -> Option<Self>;
+
+    fn zero_grad(&mut self);
+
+    fn detach(&self) -> Self;
+}
+
+impl<Dims, Kind, Dev> TensorAutograd for NamedTensor<Dims, Kind, Dev>
+where
+    Dims: DimList,
+    Kind: TensorKind,
+    Dev: TensorDevice,
+{
+    fn set_requires_grad(&self, requires_grad: bool) -> Self {
+        Self::from_tch_tensor(self.tensor.set_requires_grad(requires_grad))
+    }
+
+    fn requires_grad(&self) -> bool {
+        self.tensor.requires_grad()
+    }
+
+    fn grad(&self) -> Option<Self> {
+        let grad = self.tensor.grad();
+
+        if grad.defined() {
+            Some(Self::from_tch_tensor(grad))
+        } else {
+            None
+        }
+    }
+
+    fn zero_grad(&mut self) {
+        self.tensor.zero_grad();
+    }
+
+    fn detach(&self) -> Self {
+        Self::from_tch_tensor(self.tensor.detach())
+    }
+}
+
+// back propagation
+
+/// Back propagation from a scalar tensor. It is not implemented
+/// for non-scalar tensors.
+pub trait TensorBackward
+where
+    Self: NamedTensorTrait,
+    Self::Dimension: ScalarDim,
+{
+    fn backward(&self);
+}
+
+impl<Dims, Kind, Dev> TensorBackward for NamedTensor<Dims, Kind, Dev>
+where
+    Dims: ScalarDim,
+    Kind: TensorKind,
+    Dev: TensorDevice,
+{
+    fn backward(&self) {
+        self.tensor.backward();
+    }
+}
+
+// tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        device::Cpu,
+        kind::Double,
+        make_dims,
+        tensor::{NoKeepDim, ReduceSum},
+        DimListType,
+    };
+    use type_freak::TListType;
+    use typenum::consts::*;
+
+    make_dims! {A, B}
+
+    type Dims = DimListType! {(A, U3), (B, U2)};
+
+    #[test]
+    fn tensor_autograd_test() {
+        let tensor = NamedTensor::<Dims, Double, Cpu>::randn().set_requires_grad(true);
+        assert!(tensor.requires_grad());
+        assert!(tensor.grad().is_none());
+
+        let loss = tensor.sum::<NoKeepDim, Double, TListType! {A, B}, _>();
+        loss.backward();
+
+        let _: NamedTensor<Dims, Double, Cpu> = tensor.grad().unwrap();
+        assert!(!tensor.detach().requires_grad());
+
+        // It triggers compile error because the tensor is not a scalar
+        // tensor.backward();
+    }
+}
@@ -0,0 +1,391 @@
+use super::NamedTensor;
+use crate::{
+    device::TensorDevice,
+    dim::{BroadcastMatcher, DBroadcastBoth, DBroadcastBothOutput, DimList},
+    error::ShapeError,
+    kind::TensorKind,
+};
+
+// broadcasted binary op
+
+/// Element-wise binary operations between tensors. The output [DimList]
+/// is inferred by [DBroadcastBoth] from tail to head.
+///
+/// The arithmetic methods are prefixed by `broadcast_` to be distinguished
+/// from the [std::ops] operators, which accept identical dimensions only.
+pub trait TensorBinaryOp<LDims, RDims, Kind, Dev>
+where
+    LDims: DimList,
+    RDims: DimList,
+    Kind: TensorKind,
+    Dev: TensorDevice,
+{
+    fn broadcast_add<Matcher>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DBroadcastBothOutput<LDims, RDims, Matcher>, Kind, Dev>
+    where
+        Matcher: BroadcastMatcher,
+        LDims: DBroadcastBoth<RDims, Matcher>;
+
+    fn broadcast_sub<Matcher>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DBroadcastBothOutput<LDims, RDims, Matcher>, Kind, Dev>
+    where
+        Matcher: BroadcastMatcher,
+        LDims: DBroadcastBoth<RDims, Matcher>;
+
+    fn broadcast_mul<Matcher>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DBroadcastBothOutput<LDims, RDims, Matcher>, Kind, Dev>
+    where
+        Matcher: BroadcastMatcher,
+        LDims: DBroadcastBoth<RDims, Matcher>;
+
+    fn broadcast_div<Matcher>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DBroadcastBothOutput<LDims, RDims, Matcher>, Kind, Dev>
+    where
+        Matcher: BroadcastMatcher,
+        LDims: DBroadcastBoth<RDims, Matcher>;
+
+    /// Checks the runtime sizes before [broadcast_add](TensorBinaryOp::broadcast_add),
+    /// which is necessary if either side has [Dyn](crate::dim::Dyn) sizes.
+    fn try_broadcast_add<Matcher>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> Result<NamedTensor<DBroadcastBothOutput<LDims, RDims, Matcher>, Kind, Dev>, ShapeError>
+    where
+        Matcher: BroadcastMatcher,
+        LDims: DBroadcastBoth<RDims, Matcher>;
+
+    fn try_broadcast_sub<Matcher>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> Result<NamedTensor<DBroadcastBothOutput<LDims, RDims, Matcher>, Kind, Dev>, ShapeError>
+    where
+        Matcher: BroadcastMatcher,
+        LDims: DBroadcastBoth<RDims, Matcher>;
+
+    fn try_broadcast_mul<Matcher>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> Result<NamedTensor<DBroadcastBothOutput<LDims, RDims, Matcher>, Kind, Dev>, ShapeError>
+    where
+        Matcher: BroadcastMatcher,
+        LDims: DBroadcastBoth<RDims, Matcher>;
+
+    fn try_broadcast_div<Matcher>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> Result<NamedTensor<DBroadcastBothOutput<LDims, RDims, Matcher>, Kind, Dev>, ShapeError>
+    where
+        Matcher: BroadcastMatcher,
+        LDims: DBroadcastBoth<RDims, Matcher>;
+
+    fn maximum<Matcher>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DBroadcastBothOutput<LDims, RDims, Matcher>, Kind, Dev>
+    where
+        Matcher: BroadcastMatcher,
+        LDims: DBroadcastBoth<RDims, Matcher>;
+
+    fn minimum<Matcher>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DBroadcastBothOutput<LDims, RDims, Matcher>, Kind, Dev>
+    where
+        Matcher: BroadcastMatcher,
+        LDims: DBroadcastBoth<RDims, Matcher>;
+}
+
+impl<LDims, RDims, Kind, Dev> TensorBinaryOp<LDims, RDims, Kind, Dev>
+    for NamedTensor<LDims, Kind, Dev>
+where
+    LDims: DimList,
+    RDims: DimList,
+    Kind: TensorKind,
+    Dev: TensorDevice,
+{
+    fn broadcast_add<Matcher>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DBroadcastBothOutput<LDims, RDims, Matcher>, Kind, Dev>
+    where
+        Matcher: BroadcastMatcher,
+        LDims: DBroadcastBoth<RDims, Matcher>,
+    {
+        NamedTensor::from_tch_tensor(&self.tensor + &rhs.tensor)
+    }
+
+    fn broadcast_sub<Matcher>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DBroadcastBothOutput<LDims, RDims, Matcher>, Kind, Dev>
+    where
+        Matcher: BroadcastMatcher,
+        LDims: DBroadcastBoth<RDims, Matcher>,
+    {
+        NamedTensor::from_tch_tensor(&self.tensor - &rhs.tensor)
+    }
+
+    fn broadcast_mul<Matcher>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DBroadcastBothOutput<LDims, RDims, Matcher>, Kind, Dev>
+    where
+        Matcher: BroadcastMatcher,
+        LDims: DBroadcastBoth<RDims, Matcher>,
+    {
+        NamedTensor::from_tch_tensor(&self.tensor * &rhs.tensor)
+    }
+
+    fn broadcast_div<Matcher>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DBroadcastBothOutput<LDims, RDims, Matcher>, Kind, Dev>
+    where
+        Matcher: BroadcastMatcher,
+        LDims: DBroadcastBoth<RDims, Matcher>,
+    {
+        NamedTensor::from_tch_tensor(&self.tensor / &rhs.tensor)
+    }
+
+    fn try_broadcast_add<Matcher>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> Result<NamedTensor<DBroadcastBothOutput<LDims, RDims, Matcher>, Kind, Dev>, ShapeError>
+    where
+        Matcher: BroadcastMatcher,
+        LDims: DBroadcastBoth<RDims, Matcher>,
+    {
+        check_broadcast::<DBroadcastBothOutput<LDims, RDims, Matcher>>(
+            &self.tensor.size(),
+            &rhs.tensor.size(),
+        )?;
+        Ok(self.broadcast_add(rhs))
+    }
+
+    fn try_broadcast_sub<Matcher>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> Result<NamedTensor<DBroadcastBothOutput<LDims, RDims, Matcher>, Kind, Dev>, ShapeError>
+    where
+        Matcher: BroadcastMatcher,
+        LDims: DBroadcastBoth<RDims, Matcher>,
+    {
+        check_broadcast::<DBroadcastBothOutput<LDims, RDims, Matcher>>(
+            &self.tensor.size(),
+            &rhs.tensor.size(),
+        )?;
+        Ok(self.broadcast_sub(rhs))
+    }
+
+    fn try_broadcast_mul<Matcher>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> Result<NamedTensor<DBroadcastBothOutput<LDims, RDims, Matcher>, Kind, Dev>, ShapeError>
+    where
+        Matcher: BroadcastMatcher,
+        LDims: DBroadcastBoth<RDims, Matcher>,
+    {
+        check_broadcast::<DBroadcastBothOutput<LDims, RDims, Matcher>>(
+            &self.tensor.size(),
+            &rhs.tensor.size(),
+        )?;
+        Ok(self.broadcast_mul(rhs))
+    }
+
+    fn try_broadcast_div<Matcher>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> Result<NamedTensor<DBroadcastBothOutput<LDims, RDims, Matcher>, Kind, Dev>, ShapeError>
+    where
+        Matcher: BroadcastMatcher,
+        LDims: DBroadcastBoth<RDims, Matcher>,
+    {
+        check_broadcast::<DBroadcastBothOutput<LDims, RDims, Matcher>>(
+            &self.tensor.size(),
+            &rhs.tensor.size(),
+        )?;
+        Ok(self.broadcast_div(rhs))
+    }
+
+    fn maximum<Matcher>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DBroadcastBothOutput<LDims, RDims, Matcher>, Kind, Dev>
+    where
+        Matcher: BroadcastMatcher,
+        LDims: DBroadcastBoth<RDims, Matcher>,
+    {
+        NamedTensor::from_tch_tensor(self.tensor.max1(&rhs.tensor))
+    }
+
+    fn minimum<Matcher>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DBroadcastBothOutput<LDims, RDims, Matcher>, Kind, Dev>
+    where
+        Matcher: BroadcastMatcher,
+        LDims: DBroadcastBoth<RDims, Matcher>,
+    {
+        NamedTensor::from_tch_tensor(self.tensor.min1(&rhs.tensor))
+    }
+}
+
+// runtime check of broadcasting
+
+/// Checks the runtime sizes of both operands, which are aligned to `OutDims`
+/// from tail, against each other and against the static sizes of `OutDims`.
+pub(crate) fn check_broadcast<OutDims>(lhs: &[i64], rhs: &[i64]) -> Result<(), ShapeError>
+where
+    OutDims: DimList,
+{
+    let expected_shape = OutDims::shape_i64();
+    let rank = expected_shape.len();
+
+    for (index, (name, expected)) in OutDims::names()
+        .into_iter()
+        .zip(expected_shape.into_iter())
+        .enumerate()
+    {
+        let lsize = (index + lhs.len()).checked_sub(rank).map(|pos| lhs[pos]);
+        let rsize = (index + rhs.len()).checked_sub(rank).map(|pos| rhs[pos]);
+        let mismatch = |expected, actual| ShapeError::SizeMismatch {
+            name,
+            index,
+            expected,
+            actual,
+        };
+
+        // dynamic sizes are reported as -1
+        for &size in lsize.iter().chain(rsize.iter()) {
+            if size != 1 && expected != -1 && size != expected {
+                return Err(mismatch(expected, size));
+            }
+        }
+
+        if let (Some(lsize), Some(rsize)) = (lsize, rsize) {
+            if lsize != 1 && rsize != 1 && lsize != rsize {
+                return Err(mismatch(lsize, rsize));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// std::ops on tensors of identical dimensions
+
+// A std::ops impl cannot carry the broadcast matcher as its type parameter,
+// so the operators accept identical dimensions only. Use [TensorBinaryOp]
+// for broadcasting.
+macro_rules! impl_std_binary_op {
+    ($op_trait:ident, $op_fn:ident, $op:tt) => {
+        impl<'a, 'b, Dims, Kind, Dev> std::ops::$op_trait<&'b NamedTensor<Dims, Kind, Dev>>
+            for &'a NamedTensor<Dims, Kind, Dev>
+        where
+            Dims: DimList,
+            Kind: TensorKind,
+            Dev: TensorDevice,
+        {
+            type Output = NamedTensor<Dims, Kind, Dev>;
+
+            fn $op_fn(self, rhs: &'b NamedTensor<Dims, Kind, Dev>) -> Self::Output {
+                NamedTensor::from_tch_tensor(&self.tensor $op &rhs.tensor)
+            }
+        }
+
+        impl<Dims, Kind, Dev> std::ops::$op_trait<NamedTensor<Dims, Kind, Dev>>
+            for NamedTensor<Dims, Kind, Dev>
+        where
+            Dims: DimList,
+            Kind: TensorKind,
+            Dev: TensorDevice,
+        {
+            type Output = NamedTensor<Dims, Kind, Dev>;
+
+            fn $op_fn(self, rhs: NamedTensor<Dims, Kind, Dev>) -> Self::Output {
+                NamedTensor::from_tch_tensor(&self.tensor $op &rhs.tensor)
+            }
+        }
+    };
+}
+
+impl_std_binary_op!(Add, add, +);
+impl_std_binary_op!(Sub, sub, -);
+impl_std_binary_op!(Mul, mul, *);
+impl_std_binary_op!(Div, div, /);
+
+// tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        device::Cpu, dim::Dyn, kind::Double, make_dims, tensor::TensorFromData, DimListType,
+    };
+    use typenum::consts::*;
+
+    make_dims! {A, B, C}
+
+    type XDims = DimListType! {(A, U3), (B, U2), (C, U1)};
+    type YDims = DimListType! {(B, U1), (C, U4)};
+    type ZDims = DimListType! {(A, U3), (B, U2), (C, U4)};
+
+    #[test]
+    fn tensor_binary_op_test() {
+        let x = NamedTensor::<XDims, Double, Cpu>::zeros();
+        let y = NamedTensor::<YDims, Double, Cpu>::zeros();
+        let z = NamedTensor::<ZDims, Double, Cpu>::zeros();
+
+        // broadcasted ops
+        let _: NamedTensor<ZDims, Double, Cpu> = x.broadcast_add(&y);
+        let _: NamedTensor<ZDims, Double, Cpu> = x.broadcast_sub(&y);
+        let _: NamedTensor<ZDims, Double, Cpu> = y.broadcast_mul(&x);
+        let _: NamedTensor<ZDims, Double, Cpu> = y.broadcast_div(&x);
+        let _: NamedTensor<ZDims, Double, Cpu> = x.maximum(&z);
+        let _: NamedTensor<ZDims, Double, Cpu> = z.minimum(&y);
+
+        // broadcasted values
+        let x =
+            NamedTensor::<XDims, Double, Cpu>::from_slice(&[0.0, 1.0, 2.0, 3.0, 4.0, 5.0]).unwrap();
+        let y = NamedTensor::<YDims, Double, Cpu>::from_slice(&[10.0, 20.0, 30.0, 40.0]).unwrap();
+        let sum: NamedTensor<ZDims, Double, Cpu> = x.broadcast_add(&y);
+        assert_eq!(sum.tensor.double_value(&[0, 0, 0]), 10.0);
+        assert_eq!(sum.tensor.double_value(&[1, 0, 2]), 32.0);
+        assert_eq!(sum.tensor.double_value(&[2, 1, 3]), 45.0);
+        let diff: NamedTensor<ZDims, Double, Cpu> = y.broadcast_sub(&x);
+        assert_eq!(diff.tensor.double_value(&[2, 1, 3]), 35.0);
+
+        // runtime check of dynamic sizes
+        let dynamic =
+            NamedTensor::<DimListType! {(B, Dyn), (C, U1)}, Double, Cpu>::zeros_dyn(&[2]).unwrap();
+        let _: NamedTensor<ZDims, Double, Cpu> = z.try_broadcast_add(&dynamic).unwrap();
+        let dynamic =
+            NamedTensor::<DimListType! {(B, Dyn), (C, U1)}, Double, Cpu>::zeros_dyn(&[1]).unwrap();
+        assert!(z.try_broadcast_mul(&dynamic).is_ok());
+        let dynamic =
+            NamedTensor::<DimListType! {(B, Dyn), (C, U1)}, Double, Cpu>::zeros_dyn(&[5]).unwrap();
+        assert_eq!(
+            z.try_broadcast_sub(&dynamic).err(),
+            Some(ShapeError::SizeMismatch {
+                name: "B",
+                index: 1,
+                expected: 2,
+                actual: 5
+            })
+        );
+
+        // operators on identical dims
+        let _: NamedTensor<ZDims, Double, Cpu> = &z + &z;
+        let _: NamedTensor<ZDims, Double, Cpu> = &z - &z;
+        let _: NamedTensor<ZDims, Double, Cpu> = &z * &z;
+        let _: NamedTensor<ZDims, Double, Cpu> = z.zeros_like() / z;
+    }
+}
@@ -0,0 +1,269 @@
+use super::NamedTensor;
+use crate::{
+    device::TensorDevice,
+    dim::{BroadcastMatcher, DBroadcastBoth, DBroadcastBothOutput, DNil, DimList},
+    kind::{Bool, TensorKind},
+};
+
+// broadcasted comparison op
+
+/// Element-wise comparisons between tensors, producing [Bool] masks.
+/// The output [DimList] is inferred by [DBroadcastBoth] from tail to head.
+pub trait TensorCompareOp<LDims, RDims, Kind, Dev>
+where
+    LDims: DimList,
+    RDims: DimList,
+    Kind: TensorKind,
+    Dev: TensorDevice,
+{
+    fn eq<Matcher>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DBroadcastBothOutput<LDims, RDims, Matcher>, Bool, Dev>
+    where
+        Matcher: BroadcastMatcher,
+        LDims: DBroadcastBoth<RDims, Matcher>;
+
+    fn ne<Matcher>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DBroadcastBothOutput<LDims, RDims, Matcher>, Bool, Dev>
+    where
+        Matcher: BroadcastMatcher,
+        LDims: DBroadcastBoth<RDims, Matcher>;
+
+    fn lt<Matcher>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DBroadcastBothOutput<LDims, RDims, Matcher>, Bool, Dev>
+    where
+        Matcher: BroadcastMatcher,
+        LDims: DBroadcastBoth<RDims, Matcher>;
+
+    fn gt<Matcher>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DBroadcastBothOutput<LDims, RDims, Matcher>, Bool, Dev>
+    where
+        Matcher: BroadcastMatcher,
+        LDims: DBroadcastBoth<RDims, Matcher>;
+
+    fn le<Matcher>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DBroadcastBothOutput<LDims, RDims, Matcher>, Bool, Dev>
+    where
+        Matcher: BroadcastMatcher,
+        LDims: DBroadcastBoth<RDims, Matcher>;
+
+    fn ge<Matcher>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DBroadcastBothOutput<LDims, RDims, Matcher>, Bool, Dev>
+    where
+        Matcher: BroadcastMatcher,
+        LDims: DBroadcastBoth<RDims, Matcher>;
+}
+
+impl<LDims, RDims, Kind, Dev> TensorCompareOp<LDims, RDims, Kind, Dev>
+    for NamedTensor<LDims, Kind, Dev>
+where
+    LDims: DimList,
+    RDims: DimList,
+    Kind: TensorKind,
+    Dev: TensorDevice,
+{
+    fn eq<Matcher>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DBroadcastBothOutput<LDims, RDims, Matcher>, Bool, Dev>
+    where
+        Matcher: BroadcastMatcher,
+        LDims: DBroadcastBoth<RDims, Matcher>,
+    {
+        NamedTensor::from_tch_tensor(self.tensor.eq1(&rhs.tensor))
+    }
+
+    fn ne<Matcher>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DBroadcastBothOutput<LDims, RDims, Matcher>, Bool, Dev>
+    where
+        Matcher: BroadcastMatcher,
+        LDims: DBroadcastBoth<RDims, Matcher>,
+    {
+        NamedTensor::from_tch_tensor(self.tensor.ne1(&rhs.tensor))
+    }
+
+    fn lt<Matcher>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DBroadcastBothOutput<LDims, RDims, Matcher>, Bool, Dev>
+    where
+        Matcher: BroadcastMatcher,
+        LDims: DBroadcastBoth<RDims, Matcher>,
+    {
+        NamedTensor::from_tch_tensor(self.tensor.lt1(&rhs.tensor))
+    }
+
+    fn gt<Matcher>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DBroadcastBothOutput<LDims, RDims, Matcher>, Bool, Dev>
+    where
+        Matcher: BroadcastMatcher,
+        LDims: DBroadcastBoth<RDims, Matcher>,
+    {
+        NamedTensor::from_tch_tensor(self.tensor.gt1(&rhs.tensor))
+    }
+
+    fn le<Matcher>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DBroadcastBothOutput<LDims, RDims, Matcher>, Bool, Dev>
+    where
+        Matcher: BroadcastMatcher,
+        LDims: DBroadcastBoth<RDims, Matcher>,
+    {
+        NamedTensor::from_tch_tensor(self.tensor.le1(&rhs.tensor))
+    }
+
+    fn ge<Matcher>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DBroadcastBothOutput<LDims, RDims, Matcher>, Bool, Dev>
+    where
+        Matcher: BroadcastMatcher,
+        LDims: DBroadcastBoth<RDims, Matcher>,
+    {
+        NamedTensor::from_tch_tensor(self.tensor.ge1(&rhs.tensor))
+    }
+}
+
+// logical op on masks
+
+/// Logical operations on [Bool] masks.
+pub trait TensorLogicalOp<LDims, Dev>
+where
+    LDims: DimList,
+    Dev: TensorDevice,
+{
+    fn logical_and<RDims, Matcher>(
+        &self,
+        rhs: &NamedTensor<RDims, Bool, Dev>,
+    ) -> NamedTensor<DBroadcastBothOutput<LDims, RDims, Matcher>, Bool, Dev>
+    where
+        RDims: DimList,
+        Matcher: BroadcastMatcher,
+        LDims: DBroadcastBoth<RDims, Matcher>;
+
+    fn logical_not(&self) -> NamedTensor<LDims, Bool, Dev>;
+
+    fn all(&self) -> NamedTensor<DNil, Bool, Dev>;
+
+    fn any(&self) -> NamedTensor<DNil, Bool, Dev>;
+}
+
+impl<LDims, Dev> TensorLogicalOp<LDims, Dev> for NamedTensor<LDims, Bool, Dev>
+where
+    LDims: DimList,
+    Dev: TensorDevice,
+{
+    fn logical_and<RDims, Matcher>(
+        &self,
+        rhs: &NamedTensor<RDims, Bool, Dev>,
+    ) -> NamedTensor<DBroadcastBothOutput<LDims, RDims, Matcher>, Bool, Dev>
+    where
+        RDims: DimList,
+        Matcher: BroadcastMatcher,
+        LDims: DBroadcastBoth<RDims, Matcher>,
+    {
+        NamedTensor::from_tch_tensor(self.tensor.logical_and(&rhs.tensor))
+    }
+
+    fn logical_not(&self) -> NamedTensor<LDims, Bool, Dev> {
+        NamedTensor::from_tch_tensor(self.tensor.logical_not())
+    }
+
+    fn all(&self) -> NamedTensor<DNil, Bool, Dev> {
+        NamedTensor::from_tch_tensor(self.tensor.all())
+    }
+
+    fn any(&self) -> NamedTensor<DNil, Bool, Dev> {
+        NamedTensor::from_tch_tensor(self.tensor.any())
+    }
+}
+
+// tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        device::Cpu,
+        kind::{Double, Int64},
+        make_dims,
+        tensor::{TensorFromData, TensorToData},
+        DimListType,
+    };
+    use typenum::consts::*;
+
+    make_dims! {A, B, C}
+
+    type XDims = DimListType! {(A, U3), (B, U2), (C, U1)};
+    type YDims = DimListType! {(B, U1), (C, U4)};
+    type ZDims = DimListType! {(A, U3), (B, U2), (C, U4)};
+
+    #[test]
+    fn tensor_compare_op_test() {
+        let x = NamedTensor::<XDims, Double, Cpu>::zeros();
+        let y = NamedTensor::<YDims, Double, Cpu>::zeros();
+
+        // comparisons
+        let eq: NamedTensor<ZDims, Bool, Cpu> = x.eq(&y);
+        let _: NamedTensor<ZDims, Bool, Cpu> = x.ne(&y);
+        let lt: NamedTensor<ZDims, Bool, Cpu> = y.lt(&x);
+        let _: NamedTensor<ZDims, Bool, Cpu> = y.gt(&x);
+        let _: NamedTensor<ZDims, Bool, Cpu> = x.le(&y);
+        let _: NamedTensor<ZDims, Bool, Cpu> = x.ge(&y);
+
+        // logical ops
+        let _: NamedTensor<ZDims, Bool, Cpu> = eq.logical_and(&lt);
+        let _: NamedTensor<ZDims, Bool, Cpu> = lt.logical_not();
+        let _: NamedTensor<DimListType! {}, Bool, Cpu> = eq.all();
+        let _: NamedTensor<DimListType! {}, Bool, Cpu> = lt.any();
+    }
+
+    #[test]
+    fn tensor_compare_op_value_test() {
+        let x = NamedTensor::<DimListType! {(A, U2), (B, U3)}, Int64, Cpu>::from_array(&[
+            [0, 1, 2],
+            [3, 4, 5],
+        ]);
+        let y = NamedTensor::<DimListType! {(B, U3)}, Int64, Cpu>::from_array(&[2, 2, 2]);
+
+        // comparisons broadcast y over A
+        let lt = x.lt(&y);
+        assert_eq!(lt.to_vec(), vec![true, true, false, false, false, false]);
+        let eq = x.eq(&y);
+        assert_eq!(eq.to_vec(), vec![false, false, true, false, false, false]);
+        let ge = x.ge(&y);
+        assert_eq!(ge.to_vec(), vec![false, false, true, true, true, true]);
+
+        // logical ops
+        let odd = NamedTensor::<DimListType! {(A, U2), (B, U3)}, Bool, Cpu>::from_array(&[
+            [false, true, false],
+            [true, false, true],
+        ]);
+        assert_eq!(
+            lt.logical_and(&odd).to_vec(),
+            vec![false, true, false, false, false, false]
+        );
+        assert_eq!(
+            lt.logical_not().to_vec(),
+            vec![false, false, true, true, true, true]
+        );
+        assert_eq!(lt.all().to_vec(), vec![false]);
+        assert_eq!(lt.any().to_vec(), vec![true]);
+    }
+}
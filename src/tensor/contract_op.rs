@@ -0,0 +1,127 @@
+use super::NamedTensor;
+use crate::{
+    device::TensorDevice,
+    dim::{DContract, DContractOutput, DimList},
+    kind::TensorKind,
+};
+use tch::Tensor;
+use type_freak::list::{LNil, TList};
+
+// contraction by names
+
+/// Contraction over dimensions by names, which is lowered to [Tensor::einsum].
+pub trait TensorContractOp<LDims, RDims, Kind, Dev>
+where
+    LDims: DimList,
+    RDims: DimList,
+    Kind: TensorKind,
+    Dev: TensorDevice,
+{
+    /// Sums over the products along `Targets` dimensions shared by both tensors.
+    /// The output keeps the other dimensions of `self` followed by those of `rhs`,
+    /// which names must be distinct. It panics if the tensors have more than 52
+    /// distinct dimensions in total, which is the limit of einsum subscripts.
+    fn contract<Targets, Indexes>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DContractOutput<LDims, RDims, LNil, Targets, Indexes>, Kind, Dev>
+    where
+        Targets: TList,
+        LDims: DContract<RDims, LNil, Targets, Indexes>;
+
+    /// Sums over the products along `Targets` dimensions, while the `Batches`
+    /// dimensions shared by both tensors are kept once in front of the output.
+    /// Non-contracted names appearing in both tensors must be listed in `Batches`.
+    /// It panics if the tensors have more than 52 distinct dimensions in total.
+    fn batch_contract<Batches, Targets, Indexes>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DContractOutput<LDims, RDims, Batches, Targets, Indexes>, Kind, Dev>
+    where
+        Batches: TList,
+        Targets: TList,
+        LDims: DContract<RDims, Batches, Targets, Indexes>;
+}
+
+impl<LDims, RDims, Kind, Dev> TensorContractOp<LDims, RDims, Kind, Dev>
+    for NamedTensor<LDims, Kind, Dev>
+where
+    LDims: DimList,
+    RDims: DimList,
+    Kind: TensorKind,
+    Dev: TensorDevice,
+{
+    fn contract<Targets, Indexes>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DContractOutput<LDims, RDims, LNil, Targets, Indexes>, Kind, Dev>
+    where
+        Targets: TList,
+        LDims: DContract<RDims, LNil, Targets, Indexes>,
+    {
+        self.batch_contract::<LNil, Targets, Indexes>(rhs)
+    }
+
+    fn batch_contract<Batches, Targets, Indexes>(
+        &self,
+        rhs: &NamedTensor<RDims, Kind, Dev>,
+    ) -> NamedTensor<DContractOutput<LDims, RDims, Batches, Targets, Indexes>, Kind, Dev>
+    where
+        Batches: TList,
+        Targets: TList,
+        LDims: DContract<RDims, Batches, Targets, Indexes>,
+    {
+        let equation = <LDims as DContract<RDims, Batches, Targets, Indexes>>::equation();
+        NamedTensor::from_tch_tensor(Tensor::einsum(&equation, &[&self.tensor, &rhs.tensor]))
+    }
+}
+
+// tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{device::Cpu, dim::DNil, kind::Double, make_dims, DimListType};
+    use type_freak::TListType;
+    use typenum::consts::*;
+
+    make_dims! {Batch, Query, Key, Feature, Hidden}
+
+    #[test]
+    fn tensor_contract_op_test() {
+        // attention scores
+        let query = NamedTensor::<DimListType! {(Query, U5), (Feature, U8)}, Double, Cpu>::zeros();
+        let key = NamedTensor::<DimListType! {(Feature, U8), (Key, U7)}, Double, Cpu>::zeros();
+        let _: NamedTensor<DimListType! {(Query, U5), (Key, U7)}, Double, Cpu> =
+            query.contract::<TListType! {Feature}, _>(&key);
+
+        // bilinear form
+        let x = NamedTensor::<DimListType! {(Feature, U8)}, Double, Cpu>::zeros();
+        let y = NamedTensor::<DimListType! {(Hidden, U3)}, Double, Cpu>::zeros();
+        let weight =
+            NamedTensor::<DimListType! {(Hidden, U3), (Feature, U8)}, Double, Cpu>::zeros();
+        let _: NamedTensor<DNil, Double, Cpu> = x
+            .contract::<TListType! {Feature}, _>(&weight)
+            .contract::<TListType! {Hidden}, _>(&y);
+
+        // batched attention scores
+        let query = NamedTensor::<
+            DimListType! {(Batch, U2), (Query, U5), (Feature, U8)},
+            Double,
+            Cpu,
+        >::randn();
+        let key =
+            NamedTensor::<DimListType! {(Batch, U2), (Key, U7), (Feature, U8)}, Double, Cpu>::randn(
+            );
+        let scores: NamedTensor<DimListType! {(Batch, U2), (Query, U5), (Key, U7)}, Double, Cpu> =
+            query.batch_contract::<TListType! {Batch}, TListType! {Feature}, _>(&key);
+        let expect = query.tensor.matmul(&key.tensor.transpose(1, 2));
+        assert!(scores.tensor.allclose(&expect, 1e-5, 1e-8, false));
+
+        // It triggers compile error because Batch is shared but not listed in batches
+        // let _ = query.contract::<TListType! {Feature}, _>(&key);
+
+        // It triggers compile error because Key is missing on the left
+        // let _ = query.contract::<TListType! {Key}, _>(&key);
+    }
+}
@@ -0,0 +1,239 @@
+use super::{NamedTensor, NamedTensorTrait};
+use crate::{
+    array::FixedArray,
+    device::TensorDevice,
+    dim::{DCons, DNestedVec, DNestedVecOutput, DNil, Dim, DimList, StaticDimList},
+    error::ShapeError,
+    kind::TensorKind,
+};
+use std::convert::TryFrom;
+use tch::{kind::Element, Tensor};
+use typenum::Unsigned;
+
+// conversion from tch tensor
+
+impl<Dims, Kind, Dev> TryFrom<Tensor> for NamedTensor<Dims, Kind, Dev>
+where
+    Dims: DimList,
+    Kind: TensorKind,
+    Dev: TensorDevice,
+{
+    type Error = ShapeError;
+
+    fn try_from(tensor: Tensor) -> Result<Self, Self::Error> {
+        Self::try_from_tch(tensor)
+    }
+}
+
+// nested array with typed shape
+
+/// A trait for nested fixed-size arrays, which nesting levels and
+/// lengths agree with the [DimList] in compile time.
+pub trait TensorArray<Dims, Elem>
+where
+    Dims: DimList,
+{
+    fn append_elements(&self, prev: &mut Vec<Elem>);
+}
+
+impl<Elem> TensorArray<DNil, Elem> for Elem
+where
+    Elem: Clone,
+{
+    fn append_elements(&self, prev: &mut Vec<Elem>) {
+        prev.push(self.clone());
+    }
+}
+
+impl<Name, Size, Tail, Elem, Array> TensorArray<DCons<Name, Size, Tail>, Elem> for Array
+where
+    Name: Dim,
+    Size: Unsigned,
+    Tail: DimList,
+    Array: FixedArray<Length = Size>,
+    Array::Item: TensorArray<Tail, Elem>,
+{
+    fn append_elements(&self, prev: &mut Vec<Elem>) {
+        for item in self.as_slice() {
+            item.append_elements(prev);
+        }
+    }
+}
+
+// construction from host data
+
+/// Creates tensors from host data. It is available only for
+/// [StaticDimList]s, while tensors with [Dyn](crate::dim::Dyn) sizes can be
+/// created by [try_from_tch](NamedTensor::try_from_tch).
+pub trait TensorFromData<Kind>
+where
+    Kind: TensorKind,
+    Kind::Type: Element,
+    Self: NamedTensorTrait + Sized,
+{
+    /// Creates a tensor from a flat slice in the order of dimensions.
+    /// The slice length must equal to the number of elements.
+    fn from_slice(data: &[Kind::Type]) -> Result<Self, ShapeError>;
+
+    /// Creates a tensor from nested arrays, which lengths are checked
+    /// against the dimension sizes in compile time.
+    fn from_array<Array>(data: &Array) -> Self
+    where
+        Array: TensorArray<Self::Dimension, Kind::Type>;
+}
+
+impl<Dims, Kind, Dev> TensorFromData<Kind> for NamedTensor<Dims, Kind, Dev>
+where
+    Dims: StaticDimList,
+    Kind: TensorKind,
+    Kind::Type: Element,
+    Dev: TensorDevice,
+{
+    fn from_slice(data: &[Kind::Type]) -> Result<Self, ShapeError> {
+        let shape = Dims::shape_i64();
+        let numel = Dims::shape_usize().into_iter().product::<usize>();
+
+        if data.len() != numel {
+            return Err(ShapeError::LengthMismatch {
+                expected: numel,
+                actual: data.len(),
+            });
+        }
+
+        let tensor = Tensor::of_slice(data).view(&shape).to_device(Self::DEVICE);
+        Ok(Self::from_tch_tensor(tensor))
+    }
+
+    fn from_array<Array>(data: &Array) -> Self
+    where
+        Array: TensorArray<Dims, Kind::Type>,
+    {
+        let mut elements = vec![];
+        data.append_elements(&mut elements);
+
+        let shape = Dims::shape_i64();
+        let tensor = Tensor::of_slice(&elements)
+            .view(&shape)
+            .to_device(Self::DEVICE);
+        Self::from_tch_tensor(tensor)
+    }
+}
+
+// export to host data
+
+pub trait TensorToData<Kind>
+where
+    Kind: TensorKind,
+    Kind::Type: Element,
+    Self: NamedTensorTrait,
+{
+    /// Copies the elements to a flat [Vec] in the order of dimensions.
+    fn to_vec(&self) -> Vec<Kind::Type>;
+
+    /// Copies the elements to nested [Vec]s, which nesting levels
+    /// follow the dimensions.
+    fn to_nested(&self) -> DNestedVecOutput<Self::Dimension, Kind::Type>
+    where
+        Self::Dimension: DNestedVec<Kind::Type>;
+}
+
+impl<Dims, Kind, Dev> TensorToData<Kind> for NamedTensor<Dims, Kind, Dev>
+where
+    Dims: DimList,
+    Kind: TensorKind,
+    Kind::Type: Element,
+    Dev: TensorDevice,
+{
+    fn to_vec(&self) -> Vec<Kind::Type> {
+        Vec::<Kind::Type>::from(&self.tensor.reshape(&[-1]))
+    }
+
+    fn to_nested(&self) -> DNestedVecOutput<Dims, Kind::Type>
+    where
+        Dims: DNestedVec<Kind::Type>,
+    {
+        let mut elements = self.to_vec().into_iter();
+        Dims::from_elements(&mut elements)
+    }
+}
+
+// tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        device::Cpu,
+        kind::{Double, Float, Int64},
+        make_dims, DimListType,
+    };
+    use tch::{Device, Kind};
+    use typenum::consts::*;
+
+    make_dims! {A, B}
+
+    type Dims = DimListType! {(A, U3), (B, U2)};
+
+    #[test]
+    fn tensor_try_from_test() {
+        let tensor = Tensor::zeros(&[3, 2], (Kind::Double, Device::Cpu));
+        assert!(NamedTensor::<Dims, Double, Cpu>::try_from(tensor).is_ok());
+
+        let tensor = Tensor::zeros(&[3], (Kind::Double, Device::Cpu));
+        assert_eq!(
+            NamedTensor::<Dims, Double, Cpu>::try_from_tch(tensor).err(),
+            Some(ShapeError::RankMismatch {
+                expected: 2,
+                actual: 1
+            })
+        );
+
+        let tensor = Tensor::zeros(&[3, 4], (Kind::Double, Device::Cpu));
+        assert_eq!(
+            NamedTensor::<Dims, Double, Cpu>::try_from_tch(tensor).err(),
+            Some(ShapeError::SizeMismatch {
+                name: "B",
+                index: 1,
+                expected: 2,
+                actual: 4
+            })
+        );
+
+        let tensor = Tensor::zeros(&[3, 2], (Kind::Double, Device::Cpu));
+        assert_eq!(
+            NamedTensor::<Dims, Float, Cpu>::try_from_tch(tensor).err(),
+            Some(ShapeError::KindMismatch {
+                expected: Kind::Float,
+                actual: Kind::Double
+            })
+        );
+    }
+
+    #[test]
+    fn tensor_from_data_test() {
+        let data = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+        assert!(NamedTensor::<Dims, Double, Cpu>::from_slice(&data).is_ok());
+        assert_eq!(
+            NamedTensor::<Dims, Double, Cpu>::from_slice(&data[1..]).err(),
+            Some(ShapeError::LengthMismatch {
+                expected: 6,
+                actual: 5
+            })
+        );
+
+        let _ = NamedTensor::<Dims, Int64, Cpu>::from_array(&[[0, 1], [2, 3], [4, 5]]);
+
+        // It triggers compile error because the lengths are transposed
+        // let _ = NamedTensor::<Dims, Int64, Cpu>::from_array(&[[0, 1, 2], [3, 4, 5]]);
+    }
+
+    #[test]
+    fn tensor_to_data_test() {
+        let tensor = NamedTensor::<Dims, Int64, Cpu>::from_array(&[[0, 1], [2, 3], [4, 5]]);
+        assert_eq!(tensor.to_vec(), vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(tensor.to_nested(), vec![vec![0, 1], vec![2, 3], vec![4, 5]]);
+
+        let scalar = NamedTensor::<DimListType! {}, Int64, Cpu>::from_slice(&[7]).unwrap();
+        assert_eq!(scalar.to_nested(), 7);
+    }
+}
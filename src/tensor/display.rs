@@ -0,0 +1,219 @@
+use super::NamedTensor;
+use crate::{device::TensorDevice, dim::DimList, kind::TensorKind};
+use num::Complex;
+use std::fmt;
+use tch::{Kind as TchKind, Tensor};
+
+// formatting
+
+/// Tensors with more elements than this are summarized.
+const SUMMARY_THRESHOLD: i64 = 1000;
+
+/// The number of leading and trailing items kept in each summarized dimension.
+const EDGE_ITEMS: i64 = 3;
+
+impl<Dims, Kind, Dev> NamedTensor<Dims, Kind, Dev>
+where
+    Dims: DimList,
+    Kind: TensorKind,
+    Dev: TensorDevice,
+{
+    fn fmt_header(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let dims = Dims::names()
+            .into_iter()
+            .zip(self.tensor.size().into_iter())
+            .map(|(name, size)| format!("{}={}", name, size))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        write!(
+            f,
+            "NamedTensor[{}; {:?}; {:?}]",
+            dims,
+            Kind::KIND,
+            Dev::DEVICE
+        )
+    }
+
+    fn fmt_values(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // keep only the edge items of large tensors before copying to host
+        let (tensor, elided) = if self.tensor.numel() as i64 > SUMMARY_THRESHOLD {
+            summarize(&self.tensor)
+        } else {
+            (self.tensor.shallow_clone(), vec![false; self.tensor.dim()])
+        };
+        let shape = tensor
+            .size()
+            .into_iter()
+            .map(|size| size as usize)
+            .collect::<Vec<_>>();
+        let flat = tensor.reshape(&[-1]);
+
+        // format by kind to keep integers exact and complex numbers intact
+        match Kind::KIND {
+            TchKind::Bool => fmt_nested(f, &shape, &elided, &Vec::<bool>::from(&flat)),
+            TchKind::Uint8 | TchKind::Int8 | TchKind::Int16 | TchKind::Int | TchKind::Int64 => {
+                let values = Vec::<i64>::from(&flat.to_kind(TchKind::Int64));
+                fmt_nested(f, &shape, &elided, &values)
+            }
+            TchKind::ComplexHalf | TchKind::ComplexFloat | TchKind::ComplexDouble => {
+                let parts = Vec::<f64>::from(
+                    &flat
+                        .to_kind(TchKind::ComplexDouble)
+                        .view_as_real()
+                        .reshape(&[-1]),
+                );
+                let values = parts
+                    .chunks(2)
+                    .map(|part| Complex::new(part[0], part[1]))
+                    .collect::<Vec<_>>();
+                fmt_nested(f, &shape, &elided, &values)
+            }
+            _ => {
+                let values = Vec::<f64>::from(&flat.to_kind(TchKind::Double));
+                fmt_nested(f, &shape, &elided, &values)
+            }
+        }
+    }
+}
+
+/// Keeps the leading and trailing [EDGE_ITEMS] of dimensions longer than
+/// twice of that, and reports which dimensions are elided.
+fn summarize(tensor: &Tensor) -> (Tensor, Vec<bool>) {
+    let mut summary = tensor.shallow_clone();
+    let elided = tensor
+        .size()
+        .into_iter()
+        .enumerate()
+        .map(|(dim, size)| {
+            let elide = size > EDGE_ITEMS * 2;
+            if elide {
+                let head = summary.narrow(dim as i64, 0, EDGE_ITEMS);
+                let tail = summary.narrow(dim as i64, size - EDGE_ITEMS, EDGE_ITEMS);
+                summary = Tensor::cat(&[head, tail], dim as i64);
+            }
+            elide
+        })
+        .collect();
+    (summary, elided)
+}
+
+fn fmt_nested<T>(
+    f: &mut fmt::Formatter,
+    shape: &[usize],
+    elided: &[bool],
+    values: &[T],
+) -> fmt::Result
+where
+    T: fmt::Display,
+{
+    match (shape.split_first(), elided.split_first()) {
+        (Some((&size, sub_shape)), Some((&elide, sub_elided))) => {
+            let stride = sub_shape.iter().product::<usize>();
+
+            write!(f, "[")?;
+            for index in 0..size {
+                if index > 0 {
+                    write!(f, ", ")?;
+                }
+                if elide && index == EDGE_ITEMS as usize {
+                    write!(f, "..., ")?;
+                }
+                fmt_nested(f, sub_shape, sub_elided, &values[(index * stride)..])?;
+            }
+            write!(f, "]")
+        }
+        _ => write!(f, "{}", values[0]),
+    }
+}
+
+impl<Dims, Kind, Dev> fmt::Debug for NamedTensor<Dims, Kind, Dev>
+where
+    Dims: DimList,
+    Kind: TensorKind,
+    Dev: TensorDevice,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_header(f)?;
+        write!(f, " ")?;
+        self.fmt_values(f)
+    }
+}
+
+impl<Dims, Kind, Dev> fmt::Display for NamedTensor<Dims, Kind, Dev>
+where
+    Dims: DimList,
+    Kind: TensorKind,
+    Dev: TensorDevice,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_header(f)?;
+        writeln!(f)?;
+        self.fmt_values(f)
+    }
+}
+
+// tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        device::Cpu,
+        kind::{Bool, ComplexDouble, Float, Int64},
+        make_dims,
+        tensor::TensorFromData,
+        DimListType,
+    };
+    use typenum::consts::*;
+
+    make_dims! {Batch, Channel}
+
+    #[test]
+    fn tensor_display_test() {
+        let tensor =
+            NamedTensor::<DimListType! {(Batch, U2), (Channel, U3)}, Int64, Cpu>::from_array(&[
+                [0, 1, 2],
+                [3, 4, 5],
+            ]);
+        assert_eq!(
+            format!("{}", tensor),
+            "NamedTensor[Batch=2, Channel=3; Int64; Cpu]\n[[0, 1, 2], [3, 4, 5]]"
+        );
+
+        let scalar = NamedTensor::<DimListType! {}, Float, Cpu>::zeros();
+        assert_eq!(format!("{:?}", scalar), "NamedTensor[; Float; Cpu] 0");
+
+        // integers beyond the precision of f64 are printed exactly
+        let large =
+            NamedTensor::<DimListType! {(Batch, U1)}, Int64, Cpu>::from_array(&[(1 << 53) + 1]);
+        assert_eq!(
+            format!("{:?}", large),
+            "NamedTensor[Batch=1; Int64; Cpu] [9007199254740993]"
+        );
+
+        let mask = NamedTensor::<DimListType! {(Batch, U2)}, Bool, Cpu>::from_array(&[true, false]);
+        assert_eq!(
+            format!("{:?}", mask),
+            "NamedTensor[Batch=2; Bool; Cpu] [true, false]"
+        );
+
+        let complex = NamedTensor::<DimListType! {(Batch, U1)}, ComplexDouble, Cpu>::zeros();
+        assert_eq!(
+            format!("{:?}", complex),
+            "NamedTensor[Batch=1; ComplexDouble; Cpu] [0+0i]"
+        );
+
+        // large tensors are summarized by the edge items
+        let large =
+            NamedTensor::<DimListType! {(Batch, U2), (Channel, U1000)}, Int64, Cpu>::try_from_tch(
+                Tensor::arange(2000, (TchKind::Int64, tch::Device::Cpu)).view([2, 1000]),
+            )
+            .unwrap();
+        assert_eq!(
+            format!("{:?}", large),
+            "NamedTensor[Batch=2, Channel=1000; Int64; Cpu] \
+             [[0, 1, 2, ..., 997, 998, 999], [1000, 1001, 1002, ..., 1997, 1998, 1999]]"
+        );
+    }
+}
@@ -1,7 +1,9 @@
 use super::{NamedTensor, NamedTensorTrait};
-use crate::{device::TensorDevice, dim::DimList, kind::TensorKind};
+use crate::{device::TensorDevice, dim::StaticDimList, kind::TensorKind};
 use tch::{Scalar as TchScalar, Tensor};
 
+/// Creates tensors filled with a value. It is available only for
+/// [StaticDimList]s.
 pub trait TensorFull<Kind>
 where
     Kind: TensorKind,
@@ -13,7 +15,7 @@ where
 
 impl<Dims, Kind, Dev> TensorFull<Kind> for NamedTensor<Dims, Kind, Dev>
 where
-    Dims: DimList,
+    Dims: StaticDimList,
     Kind: TensorKind,
     Dev: TensorDevice,
     Kind::Type: Into<TchScalar>,
@@ -0,0 +1,133 @@
+use super::NamedTensor;
+use crate::{
+    device::TensorDevice,
+    dim::{DGatherAt, DIndexSelect, DIndexSelectOutput, Dim, DimList},
+    kind::{Int64, TensorKind},
+};
+use type_freak::counter::Counter;
+
+// indexing by tensors
+
+/// Data-dependent indexing by [Int64] index tensors of [DimList] `IndexDims`.
+pub trait TensorIndexOp<Dims, IndexDims, Kind, Dev>
+where
+    Dims: DimList,
+    IndexDims: DimList,
+    Kind: TensorKind,
+    Dev: TensorDevice,
+{
+    /// Selects entries along `Target` dimension by a one-dimensional index
+    /// tensor. The `Target` dimension is replaced by that of the index.
+    fn index_select<Target, Index>(
+        &self,
+        index: &NamedTensor<IndexDims, Int64, Dev>,
+    ) -> NamedTensor<DIndexSelectOutput<Dims, Target, IndexDims, Index>, Kind, Dev>
+    where
+        Target: Dim,
+        Index: Counter,
+        Dims: DIndexSelect<Target, IndexDims, Index>;
+
+    /// Gathers values along `Target` dimension. The index tensor must agree
+    /// with the input except for the size of `Target` dimension.
+    fn gather<Target, Index>(
+        &self,
+        index: &NamedTensor<IndexDims, Int64, Dev>,
+    ) -> NamedTensor<IndexDims, Kind, Dev>
+    where
+        Target: Dim,
+        Index: Counter,
+        Dims: DGatherAt<Target, IndexDims, Index>;
+}
+
+impl<Dims, IndexDims, Kind, Dev> TensorIndexOp<Dims, IndexDims, Kind, Dev>
+    for NamedTensor<Dims, Kind, Dev>
+where
+    Dims: DimList,
+    IndexDims: DimList,
+    Kind: TensorKind,
+    Dev: TensorDevice,
+{
+    fn index_select<Target, Index>(
+        &self,
+        index: &NamedTensor<IndexDims, Int64, Dev>,
+    ) -> NamedTensor<DIndexSelectOutput<Dims, Target, IndexDims, Index>, Kind, Dev>
+    where
+        Target: Dim,
+        Index: Counter,
+        Dims: DIndexSelect<Target, IndexDims, Index>,
+    {
+        let dim = <Dims as DIndexSelect<Target, IndexDims, Index>>::INDEX as i64;
+        NamedTensor::from_tch_tensor(self.tensor.index_select(dim, &index.tensor))
+    }
+
+    fn gather<Target, Index>(
+        &self,
+        index: &NamedTensor<IndexDims, Int64, Dev>,
+    ) -> NamedTensor<IndexDims, Kind, Dev>
+    where
+        Target: Dim,
+        Index: Counter,
+        Dims: DGatherAt<Target, IndexDims, Index>,
+    {
+        let dim = <Dims as DGatherAt<Target, IndexDims, Index>>::INDEX as i64;
+        NamedTensor::from_tch_tensor(self.tensor.gather(dim, &index.tensor, false))
+    }
+}
+
+// tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        device::Cpu,
+        kind::Double,
+        make_dims,
+        tensor::{TensorFromData, TensorToData},
+        DimListType,
+    };
+    use typenum::consts::*;
+
+    make_dims! {Vocab, Word, Feature, Batch, Class}
+
+    #[test]
+    fn tensor_index_op_test() {
+        // embedding lookup
+        let table =
+            NamedTensor::<DimListType! {(Vocab, U5), (Feature, U3)}, Double, Cpu>::from_array(&[
+                [0.0, 1.0, 2.0],
+                [10.0, 11.0, 12.0],
+                [20.0, 21.0, 22.0],
+                [30.0, 31.0, 32.0],
+                [40.0, 41.0, 42.0],
+            ]);
+        let words = NamedTensor::<DimListType! {(Word, U4)}, Int64, Cpu>::from_array(&[0, 4, 2, 2]);
+        let embedded: NamedTensor<DimListType! {(Word, U4), (Feature, U3)}, Double, Cpu> =
+            table.index_select::<Vocab, _>(&words);
+        assert_eq!(
+            embedded.to_vec(),
+            vec![0.0, 1.0, 2.0, 40.0, 41.0, 42.0, 20.0, 21.0, 22.0, 20.0, 21.0, 22.0]
+        );
+
+        // label-based picking
+        let scores =
+            NamedTensor::<DimListType! {(Batch, U2), (Class, U3)}, Double, Cpu>::from_array(&[
+                [0.0, 1.0, 2.0],
+                [3.0, 4.0, 5.0],
+            ]);
+        let labels =
+            NamedTensor::<DimListType! {(Batch, U2), (Class, U1)}, Int64, Cpu>::from_array(&[
+                [2],
+                [0],
+            ]);
+        let picked: NamedTensor<DimListType! {(Batch, U2), (Class, U1)}, Double, Cpu> =
+            scores.gather::<Class, _>(&labels);
+        assert_eq!(picked.to_vec(), vec![2.0, 3.0]);
+
+        // It triggers compile error because the index is not one-dimensional
+        // let _ = table.index_select::<Vocab, _>(&labels);
+
+        // It triggers compile error because the index misses Batch dimension
+        // let _ = scores.gather::<Class, _>(&words);
+    }
+}
@@ -1,32 +1,59 @@
+mod aligned_op;
+mod autograd;
+mod binary_op;
+mod compare_op;
+mod contract_op;
+mod convert;
+mod display;
 mod full_op;
+mod index_op;
 mod keepdim;
 mod pow_op;
 mod reduction;
 mod value_at;
 
 use type_freak::{
-    control::{IfLess, IfLessOutput},
+    control::{IfLess, IfLessOrEqual, IfLessOutput},
     counter::{Count, CountOutput, Counter},
     list::TList,
 };
 
 use crate::{
+    array::{ArrayOf, ArrayOfOutput, FixedArray},
     device::TensorDevice,
     dim::{
-        BroadcastMatcher, DConcatAt, DConcatAtOutput, DFlatten, DFlattenBeginIndex,
-        DFlattenEndIndex, DFlattenOutput, DIndexOfMany, DMatMul, DMatMulBroadcasted,
-        DMatMulBroadcastedOutput, DMatMulOutput, DPermute, DPermuteOutput, DRemoveAt,
-        DRemoveAtOutput, DSizeAt, DSizeAtOutput, Dim, DimList, MatrixDim,
+        AlignMatcher, BroadcastMatcher, DAlignTo, DAlignToOutput, DAppend, DAppendOutput,
+        DConcatAt, DConcatAtOutput, DCons, DConvSize, DConvSizeOutput, DExpandAtOutput,
+        DExpandEndOutput, DExtractDim, DExtractDimOutput, DFlatten, DFlattenBeginIndex,
+        DFlattenEndIndex, DFlattenOutput, DIndexOf, DIndexOfIndex, DIndexOfMany, DInsertAt,
+        DInsertAtOutput, DLength, DLengthOutput, DMatMul, DMatMulBroadcasted,
+        DMatMulBroadcastedOutput, DMatMulOutput, DPermute, DPermuteOutput, DPool2dOutput, DPrepend,
+        DPrependOutput, DRemoveAt, DRemoveAtOutput, DRename, DRenameOutput, DReplaceAt,
+        DResizeAtOutput, DResizeSpatial2d, DResizeSpatial2dOutput, DSizeAt, DSizeAtOutput,
+        DSizeProduct, DSizeProductOutput, DSliceSize, DSpatial2d, DSpatial2dHeight,
+        DSpatial2dWidth, DSqueezeAll, DSqueezeAllOutput, DUnflatten, DUnflattenIndex,
+        DUnflattenOutput, Dim, DimList, MatrixDim, NonScalarDim, StaticDimList,
     },
-    kind::TensorKind,
+    error::ShapeError,
+    kind::{FloatKind, TensorKind},
 };
+pub use aligned_op::*;
+pub use autograd::*;
+pub use binary_op::*;
+pub use compare_op::*;
+pub use contract_op::*;
+pub use convert::*;
 pub use full_op::*;
+pub use index_op::*;
 pub use keepdim::*;
 pub use pow_op::*;
 pub use reduction::*;
 use std::marker::PhantomData;
+use std::ops::{Add, Div, Mul, Rem, Sub};
 use tch::{Device as TchDevice, Kind as TchKind, Tensor};
-use typenum::{IsLess, Unsigned};
+use typenum::{
+    Diff, IsLess, IsLessOrEqual, NonZero, Prod, Quot, Sub1, Sum, True, Unsigned, B1, U0, U1, U2,
+};
 pub use value_at::*;
 
 // convenient trait to obtain typed properties
@@ -56,7 +83,7 @@ where
 // named tensor struct
 
 pub struct NamedTensor<Dims: DimList, Kind: TensorKind, Dev: TensorDevice> {
-    tensor: Tensor,
+    pub(crate) tensor: Tensor,
     _phantom: PhantomData<(Dims, Kind, Dev)>,
 }
 
@@ -69,19 +96,118 @@ where
     const DEVICE: TchDevice = Dev::DEVICE;
     const KIND: TchKind = Kind::KIND;
 
-    pub(self) fn from_tch_tensor(tensor: Tensor) -> Self {
+    pub(crate) fn from_tch_tensor(tensor: Tensor) -> Self {
         let ret = Self {
             tensor,
             _phantom: PhantomData,
         };
 
-        debug_assert_eq!(ret.size(), ret.tensor.size());
+        debug_assert_eq!(Self::check_shape(&ret.tensor.size()), Ok(()));
         debug_assert_eq!(Self::KIND, ret.tensor.kind());
         debug_assert_eq!(Self::DEVICE, ret.tensor.device());
 
         ret
     }
 
+    /// Wraps a [Tensor] after checking its shape, kind and device
+    /// against the typed properties at runtime.
+    /// Dimensions of [Dyn](crate::dim::Dyn) size accept any size.
+    pub fn try_from_tch(tensor: Tensor) -> Result<Self, ShapeError> {
+        Self::check_shape(&tensor.size())?;
+
+        let actual_kind = tensor.kind();
+        if Self::KIND != actual_kind {
+            return Err(ShapeError::KindMismatch {
+                expected: Self::KIND,
+                actual: actual_kind,
+            });
+        }
+
+        let actual_device = tensor.device();
+        if Self::DEVICE != actual_device {
+            return Err(ShapeError::DeviceMismatch {
+                expected: Self::DEVICE,
+                actual: actual_device,
+            });
+        }
+
+        Ok(Self::from_tch_tensor(tensor))
+    }
+
+    /// Tries to change the sizes of dimensions, typically between [Dyn](crate::dim::Dyn)
+    /// and static sizes, while the names are kept. The sizes are checked at runtime.
+    pub fn try_cast_dims<NewDims>(&self) -> Result<NamedTensor<NewDims, Kind, Dev>, ShapeError>
+    where
+        Dims: DExtractDim,
+        NewDims: DExtractDim<Output = DExtractDimOutput<Dims>>,
+    {
+        NamedTensor::try_from_tch(self.tensor.shallow_clone())
+    }
+
+    fn check_shape(actual_shape: &[i64]) -> Result<(), ShapeError> {
+        let expected_shape = Dims::shape_i64();
+
+        if expected_shape.len() != actual_shape.len() {
+            return Err(ShapeError::RankMismatch {
+                expected: expected_shape.len(),
+                actual: actual_shape.len(),
+            });
+        }
+
+        for (index, ((name, expected), &actual)) in Dims::names()
+            .into_iter()
+            .zip(expected_shape.into_iter())
+            .zip(actual_shape.iter())
+            .enumerate()
+        {
+            // dynamic sizes are reported as -1
+            if expected != -1 && expected != actual {
+                return Err(ShapeError::SizeMismatch {
+                    name,
+                    index,
+                    expected,
+                    actual,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Fills the [Dyn](crate::dim::Dyn) sizes of the static shape in order.
+    /// The sizes must be non-negative.
+    fn shape_with_dyn(dyn_sizes: &[i64]) -> Result<Vec<i64>, ShapeError> {
+        let mut shape = Dims::shape_i64();
+        let num_dyn = shape.iter().filter(|size| **size == -1).count();
+
+        if num_dyn != dyn_sizes.len() {
+            return Err(ShapeError::DynCountMismatch {
+                expected: num_dyn,
+                actual: dyn_sizes.len(),
+            });
+        }
+
+        let mut dyn_sizes = dyn_sizes.iter();
+        for (index, (name, size)) in Dims::names()
+            .into_iter()
+            .zip(shape.iter_mut())
+            .enumerate()
+            .filter(|(_, (_, size))| **size == -1)
+        {
+            let dyn_size = *dyn_sizes.next().unwrap();
+            if dyn_size < 0 {
+                return Err(ShapeError::NegativeSize {
+                    name,
+                    index,
+                    actual: dyn_size,
+                });
+            }
+            *size = dyn_size;
+        }
+
+        Ok(shape)
+    }
+
     pub fn device(&self) -> TchDevice {
         Self::DEVICE
     }
@@ -90,24 +216,55 @@ where
         Self::KIND
     }
 
+    /// Returns the runtime sizes, including those of [Dyn](crate::dim::Dyn) dimensions.
     pub fn size(&self) -> Vec<i64> {
-        Dims::shape_i64()
+        self.tensor.size()
     }
 
-    pub fn zeros() -> Self {
+    /// Creates a zero tensor of static sizes. Use
+    /// [zeros_dyn](NamedTensor::zeros_dyn) if `Dims` contains [Dyn](crate::dim::Dyn) sizes.
+    pub fn zeros() -> Self
+    where
+        Dims: StaticDimList,
+    {
         let shape = Dims::shape_i64();
         Self::from_tch_tensor(Tensor::zeros(&shape, (Self::KIND, Self::DEVICE)))
     }
 
+    /// Creates a zero tensor, where `dyn_sizes` gives the sizes of
+    /// [Dyn](crate::dim::Dyn) dimensions in order.
+    pub fn zeros_dyn(dyn_sizes: &[i64]) -> Result<Self, ShapeError> {
+        let shape = Self::shape_with_dyn(dyn_sizes)?;
+        Ok(Self::from_tch_tensor(Tensor::zeros(
+            &shape,
+            (Self::KIND, Self::DEVICE),
+        )))
+    }
+
     pub fn zeros_like(&self) -> Self {
         Self::from_tch_tensor(self.tensor.zeros_like())
     }
 
-    pub fn randn() -> Self {
+    /// Creates a random tensor of static sizes. Use
+    /// [randn_dyn](NamedTensor::randn_dyn) if `Dims` contains [Dyn](crate::dim::Dyn) sizes.
+    pub fn randn() -> Self
+    where
+        Dims: StaticDimList,
+    {
         let shape = Dims::shape_i64();
         Self::from_tch_tensor(Tensor::randn(&shape, (Self::KIND, Self::DEVICE)))
     }
 
+    /// Creates a random tensor, where `dyn_sizes` gives the sizes of
+    /// [Dyn](crate::dim::Dyn) dimensions in order.
+    pub fn randn_dyn(dyn_sizes: &[i64]) -> Result<Self, ShapeError> {
+        let shape = Self::shape_with_dyn(dyn_sizes)?;
+        Ok(Self::from_tch_tensor(Tensor::randn(
+            &shape,
+            (Self::KIND, Self::DEVICE),
+        )))
+    }
+
     pub fn randn_like(&self) -> Self {
         Self::from_tch_tensor(self.tensor.randn_like())
     }
@@ -190,6 +347,62 @@ where
         NamedTensor::from_tch_tensor(self.tensor.sigmoid())
     }
 
+    pub fn relu(&self) -> Self {
+        NamedTensor::from_tch_tensor(self.tensor.relu())
+    }
+
+    /// Applies leaky ReLU with the default negative slope 0.01.
+    pub fn leaky_relu(&self) -> Self {
+        NamedTensor::from_tch_tensor(self.tensor.leaky_relu())
+    }
+
+    /// Applies leaky ReLU with the given negative slope.
+    pub fn leaky_relu_with_slope(&self, negative_slope: f64) -> Self {
+        let positive = self.tensor.clamp_min(0.0);
+        let negative = self.tensor.clamp_max(0.0) * negative_slope;
+        NamedTensor::from_tch_tensor(positive + negative)
+    }
+
+    pub fn gelu(&self) -> Self {
+        NamedTensor::from_tch_tensor(self.tensor.gelu())
+    }
+
+    pub fn elu(&self) -> Self {
+        NamedTensor::from_tch_tensor(self.tensor.elu())
+    }
+
+    pub fn silu(&self) -> Self {
+        NamedTensor::from_tch_tensor(self.tensor.silu())
+    }
+
+    pub fn softplus(&self) -> Self {
+        NamedTensor::from_tch_tensor(self.tensor.softplus())
+    }
+
+    /// Applies softmax along the `Target` dimension.
+    pub fn softmax<Target, Index>(&self) -> Self
+    where
+        Kind: FloatKind,
+        Target: Dim,
+        Index: Counter,
+        Dims: DIndexOf<Target, Index>,
+    {
+        let index = DIndexOfIndex::<Dims, Target, Index>::I64;
+        NamedTensor::from_tch_tensor(self.tensor.softmax(index, Self::KIND))
+    }
+
+    /// Applies log-softmax along the `Target` dimension.
+    pub fn log_softmax<Target, Index>(&self) -> Self
+    where
+        Kind: FloatKind,
+        Target: Dim,
+        Index: Counter,
+        Dims: DIndexOf<Target, Index>,
+    {
+        let index = DIndexOfIndex::<Dims, Target, Index>::I64;
+        NamedTensor::from_tch_tensor(self.tensor.log_softmax(index, Self::KIND))
+    }
+
     pub fn transpose<NewDims, Indexes>(
         &self,
     ) -> NamedTensor<DPermuteOutput<Dims, NewDims, Indexes>, Kind, Dev>
@@ -206,6 +419,33 @@ where
         NamedTensor::from_tch_tensor(self.tensor.permute(&indexes))
     }
 
+    /// Reorders the dimensions by names following `Target`, and inserts
+    /// size-one dimensions for names that are missing.
+    pub fn align_to<Target, Matcher>(
+        &self,
+    ) -> NamedTensor<DAlignToOutput<Dims, Target, Matcher>, Kind, Dev>
+    where
+        Target: DimList,
+        Matcher: AlignMatcher,
+        Dims: DAlignTo<Target, Matcher>,
+    {
+        let align_index = <Dims as DAlignTo<Target, Matcher>>::align_index();
+        let permute_index = align_index
+            .iter()
+            .filter_map(|index| index.map(|index| index as i64))
+            .collect::<Vec<_>>();
+
+        let tensor = align_index
+            .iter()
+            .enumerate()
+            .filter(|(_, index)| index.is_none())
+            .fold(self.tensor.permute(&permute_index), |tensor, (index, _)| {
+                tensor.unsqueeze(index as i64)
+            });
+
+        NamedTensor::from_tch_tensor(tensor)
+    }
+
     pub fn flip<SelectedDims, Indexes>(&self) -> Self
     where
         Indexes: TList,
@@ -235,6 +475,227 @@ where
         NamedTensor::from_tch_tensor(tensor)
     }
 
+    /// Checks that the runtime sizes of dimensions other than `Target` agree
+    /// before [concat](NamedTensor::concat), which is necessary if they have
+    /// [Dyn](crate::dim::Dyn) sizes.
+    pub fn try_concat<Target, Index, RDimList>(
+        &self,
+        rhs: &NamedTensor<RDimList, Kind, Dev>,
+    ) -> Result<NamedTensor<DConcatAtOutput<Dims, RDimList, Target, Index>, Kind, Dev>, ShapeError>
+    where
+        Dims: DConcatAt<RDimList, Target, Index>,
+        Target: Dim,
+        Index: Counter,
+        RDimList: DimList,
+    {
+        let target_index = <Dims as DConcatAt<RDimList, Target, Index>>::INDEX;
+
+        for (index, ((name, expected), actual)) in Dims::names()
+            .into_iter()
+            .zip(self.tensor.size())
+            .zip(rhs.tensor.size())
+            .enumerate()
+        {
+            if index != target_index && expected != actual {
+                return Err(ShapeError::SizeMismatch {
+                    name,
+                    index,
+                    expected,
+                    actual,
+                });
+            }
+        }
+
+        Ok(self.concat::<Target, Index, RDimList>(rhs))
+    }
+
+    /// Concatenates a non-empty array of tensors along `Target` dimension.
+    pub fn concat_many<'a, Target, Index, Tensors>(
+        tensors: Tensors,
+    ) -> NamedTensor<
+        DResizeAtOutput<
+            Dims,
+            Target,
+            Prod<DSizeAtOutput<Dims, Target, Index>, Tensors::Length>,
+            Index,
+        >,
+        Kind,
+        Dev,
+    >
+    where
+        Self: 'a,
+        Target: Dim,
+        Index: Counter,
+        Tensors: FixedArray<Item = &'a Self>,
+        Tensors::Length: NonZero,
+        DSizeAtOutput<Dims, Target, Index>: Mul<Tensors::Length>,
+        Prod<DSizeAtOutput<Dims, Target, Index>, Tensors::Length>: Unsigned,
+        Dims: DSizeAt<Target, Index>
+            + DReplaceAt<
+                Target,
+                Target,
+                Prod<DSizeAtOutput<Dims, Target, Index>, Tensors::Length>,
+                Index,
+            >,
+    {
+        let index = <Dims as DReplaceAt<
+            Target,
+            Target,
+            Prod<DSizeAtOutput<Dims, Target, Index>, Tensors::Length>,
+            Index,
+        >>::INDEX;
+        let tch_tensors = tensors
+            .as_slice()
+            .iter()
+            .map(|tensor| &tensor.tensor)
+            .collect::<Vec<_>>();
+        NamedTensor::from_tch_tensor(Tensor::cat(&tch_tensors, index as i64))
+    }
+
+    /// Splits into `Pieces` tensors of equal size along `Target` dimension.
+    /// The dimension size must be divisible by `Pieces`.
+    pub fn chunk<Target, Pieces, Index>(
+        &self,
+    ) -> ArrayOfOutput<
+        Pieces,
+        NamedTensor<
+            DResizeAtOutput<Dims, Target, Quot<DSizeAtOutput<Dims, Target, Index>, Pieces>, Index>,
+            Kind,
+            Dev,
+        >,
+    >
+    where
+        Target: Dim,
+        Index: Counter,
+        Pieces: Unsigned
+            + NonZero
+            + ArrayOf<
+                NamedTensor<
+                    DResizeAtOutput<
+                        Dims,
+                        Target,
+                        Quot<DSizeAtOutput<Dims, Target, Index>, Pieces>,
+                        Index,
+                    >,
+                    Kind,
+                    Dev,
+                >,
+            >,
+        DSizeAtOutput<Dims, Target, Index>: Div<Pieces> + Rem<Pieces, Output = U0>,
+        Quot<DSizeAtOutput<Dims, Target, Index>, Pieces>: Unsigned,
+        Dims: DSizeAt<Target, Index>
+            + DReplaceAt<Target, Target, Quot<DSizeAtOutput<Dims, Target, Index>, Pieces>, Index>,
+    {
+        let index = <Dims as DReplaceAt<
+            Target,
+            Target,
+            Quot<DSizeAtOutput<Dims, Target, Index>, Pieces>,
+            Index,
+        >>::INDEX;
+        let tensors = self
+            .tensor
+            .chunk(Pieces::I64, index as i64)
+            .into_iter()
+            .map(NamedTensor::from_tch_tensor)
+            .collect::<Vec<_>>();
+        Pieces::from_vec(tensors)
+    }
+
+    /// Splits into two tensors along `Target` dimension, which sizes are
+    /// `Len` and the rest respectively.
+    pub fn split_at<Target, Len, Index>(
+        &self,
+    ) -> (
+        NamedTensor<DResizeAtOutput<Dims, Target, Len, Index>, Kind, Dev>,
+        NamedTensor<
+            DResizeAtOutput<Dims, Target, Diff<DSizeAtOutput<Dims, Target, Index>, Len>, Index>,
+            Kind,
+            Dev,
+        >,
+    )
+    where
+        Target: Dim,
+        Index: Counter,
+        Len: Unsigned,
+        DSizeAtOutput<Dims, Target, Index>: Sub<Len>,
+        Diff<DSizeAtOutput<Dims, Target, Index>, Len>: Unsigned,
+        Dims: DSizeAt<Target, Index>
+            + DReplaceAt<Target, Target, Len, Index>
+            + DReplaceAt<Target, Target, Diff<DSizeAtOutput<Dims, Target, Index>, Len>, Index>,
+    {
+        let index = <Dims as DReplaceAt<Target, Target, Len, Index>>::INDEX as i64;
+        let rest_len = Diff::<DSizeAtOutput<Dims, Target, Index>, Len>::I64;
+
+        let head = self.tensor.narrow(index, 0, Len::I64);
+        let tail = self.tensor.narrow(index, Len::I64, rest_len);
+
+        (
+            NamedTensor::from_tch_tensor(head),
+            NamedTensor::from_tch_tensor(tail),
+        )
+    }
+
+    /// Stacks a non-empty array of tensors along a new `NewDim` dimension at front.
+    pub fn stack<'a, NewDim, Tensors>(
+        tensors: Tensors,
+    ) -> NamedTensor<DCons<NewDim, Tensors::Length, Dims>, Kind, Dev>
+    where
+        Self: 'a,
+        NewDim: Dim,
+        Tensors: FixedArray<Item = &'a Self>,
+        Tensors::Length: NonZero,
+    {
+        let tch_tensors = tensors
+            .as_slice()
+            .iter()
+            .map(|tensor| &tensor.tensor)
+            .collect::<Vec<_>>();
+        NamedTensor::from_tch_tensor(Tensor::stack(&tch_tensors, 0))
+    }
+
+    /// Stacks a non-empty array of tensors along a new `NewDim` dimension,
+    /// which is inserted before `Target` dimension.
+    pub fn stack_before<'a, NewDim, Target, Index, Tensors>(
+        tensors: Tensors,
+    ) -> NamedTensor<DInsertAtOutput<Dims, NewDim, Tensors::Length, Target, Index>, Kind, Dev>
+    where
+        Self: 'a,
+        NewDim: Dim,
+        Target: Dim,
+        Index: Counter,
+        Tensors: FixedArray<Item = &'a Self>,
+        Tensors::Length: NonZero,
+        Dims: DInsertAt<NewDim, Tensors::Length, Target, Index>,
+    {
+        let index = <Dims as DInsertAt<NewDim, Tensors::Length, Target, Index>>::INDEX;
+        let tch_tensors = tensors
+            .as_slice()
+            .iter()
+            .map(|tensor| &tensor.tensor)
+            .collect::<Vec<_>>();
+        NamedTensor::from_tch_tensor(Tensor::stack(&tch_tensors, index as i64))
+    }
+
+    /// Stacks a non-empty array of tensors along a new `NewDim` dimension at end.
+    pub fn stack_end<'a, NewDim, Tensors>(
+        tensors: Tensors,
+    ) -> NamedTensor<DAppendOutput<Dims, NewDim, Tensors::Length>, Kind, Dev>
+    where
+        Self: 'a,
+        NewDim: Dim,
+        Tensors: FixedArray<Item = &'a Self>,
+        Tensors::Length: NonZero,
+        Dims: DAppend<NewDim, Tensors::Length> + DLength,
+    {
+        let index = DLengthOutput::<Dims>::I64;
+        let tch_tensors = tensors
+            .as_slice()
+            .iter()
+            .map(|tensor| &tensor.tensor)
+            .collect::<Vec<_>>();
+        NamedTensor::from_tch_tensor(Tensor::stack(&tch_tensors, index))
+    }
+
     pub fn flatten<NewDim, BeginDim, EndDim, BeginIndex, EndIndex>(
         &self,
     ) -> NamedTensor<DFlattenOutput<Dims, NewDim, BeginDim, EndDim, BeginIndex, EndIndex>, Kind, Dev>
@@ -254,6 +715,54 @@ where
         NamedTensor::from_tch_tensor(self.tensor.flatten(begin_index, end_index))
     }
 
+    /// Splits the `Target` dimension into `NewDims`. It is the inverse of
+    /// [flatten](NamedTensor::flatten).
+    pub fn unflatten<Target, NewDims, Index>(
+        &self,
+    ) -> NamedTensor<DUnflattenOutput<Dims, Target, NewDims, Index>, Kind, Dev>
+    where
+        Target: Dim,
+        NewDims: NonScalarDim,
+        Index: Counter,
+        Dims: DUnflatten<Target, NewDims, Index>,
+    {
+        let index = DUnflattenIndex::<Dims, Target, NewDims, Index>::I64;
+        NamedTensor::from_tch_tensor(self.tensor.unflatten(index, &NewDims::shape_i64()))
+    }
+
+    /// Reshapes to `NewDims`, which number of elements must agree with
+    /// that of `Dims` in compile time.
+    pub fn reshape<NewDims>(&self) -> NamedTensor<NewDims, Kind, Dev>
+    where
+        Dims: DSizeProduct,
+        NewDims: DimList + DSizeProduct<Output = DSizeProductOutput<Dims>>,
+    {
+        NamedTensor::from_tch_tensor(self.tensor.reshape(&NewDims::shape_i64()))
+    }
+
+    /// Views the data as `NewDims` without copying, which number of elements
+    /// must agree with that of `Dims` in compile time.
+    pub fn view<NewDims>(&self) -> NamedTensor<NewDims, Kind, Dev>
+    where
+        Dims: DSizeProduct,
+        NewDims: DimList + DSizeProduct<Output = DSizeProductOutput<Dims>>,
+    {
+        NamedTensor::from_tch_tensor(self.tensor.view(&NewDims::shape_i64()))
+    }
+
+    /// Renames the `Old` dimension to `New`. The underlying data is shared
+    /// with the input. It fails to compile if `New` already exists.
+    pub fn rename<Old, New, Index>(
+        &self,
+    ) -> NamedTensor<DRenameOutput<Dims, Old, New, Index>, Kind, Dev>
+    where
+        Old: Dim,
+        New: Dim,
+        Dims: DRename<Old, New, Index>,
+    {
+        NamedTensor::from_tch_tensor(self.tensor.shallow_clone())
+    }
+
     pub fn mm<RhsDims>(
         &self,
         rhs: NamedTensor<RhsDims, Kind, Dev>,
@@ -289,18 +798,525 @@ where
         let target_index = CountOutput::<Index>::I64;
         NamedTensor::from_tch_tensor(self.tensor.select(target_index as i64, Position::to_i64()))
     }
+
+    /// Takes `Len` elements from `Start` along `Target` dimension. It is checked
+    /// in compile time that the range is bounded by the dimension size.
+    pub fn narrow<Target, Start, Len, Index>(
+        &self,
+    ) -> NamedTensor<DResizeAtOutput<Dims, Target, Len, Index>, Kind, Dev>
+    where
+        Target: Dim,
+        Index: Counter,
+        Start: Unsigned + Add<Len>,
+        Len: Unsigned,
+        Sum<Start, Len>:
+            Unsigned + IfLessOrEqual<Sum<Start, Len>, DSizeAtOutput<Dims, Target, Index>>,
+        Dims: DSizeAt<Target, Index> + DReplaceAt<Target, Target, Len, Index>,
+    {
+        let index = <Dims as DReplaceAt<Target, Target, Len, Index>>::INDEX;
+        NamedTensor::from_tch_tensor(self.tensor.narrow(index as i64, Start::I64, Len::I64))
+    }
+
+    /// Takes elements from `Start` to `End` exclusively with `Step` along
+    /// `Target` dimension. It is checked in compile time that the range is
+    /// bounded by the dimension size.
+    pub fn slice<Target, Start, End, Step, Index>(
+        &self,
+    ) -> NamedTensor<DResizeAtOutput<Dims, Target, DSliceSize<Start, End, Step>, Index>, Kind, Dev>
+    where
+        Target: Dim,
+        Index: Counter,
+        Start: Unsigned + IfLessOrEqual<Start, End>,
+        End: Unsigned + Sub<Start> + IfLessOrEqual<End, DSizeAtOutput<Dims, Target, Index>>,
+        Step: Unsigned + NonZero,
+        Diff<End, Start>: Add<Step>,
+        Sum<Diff<End, Start>, Step>: Sub<B1>,
+        Sub1<Sum<Diff<End, Start>, Step>>: Div<Step>,
+        DSliceSize<Start, End, Step>: Unsigned,
+        Dims: DSizeAt<Target, Index>
+            + DReplaceAt<Target, Target, DSliceSize<Start, End, Step>, Index>,
+    {
+        let index =
+            <Dims as DReplaceAt<Target, Target, DSliceSize<Start, End, Step>, Index>>::INDEX;
+        NamedTensor::from_tch_tensor(self.tensor.slice(
+            index as i64,
+            Start::I64,
+            End::I64,
+            Step::I64,
+        ))
+    }
+
+    pub fn unsqueeze_before<NewDim, Target, Index>(
+        &self,
+    ) -> NamedTensor<DExpandAtOutput<Dims, NewDim, Target, Index>, Kind, Dev>
+    where
+        NewDim: Dim,
+        Target: Dim,
+        Index: Counter,
+        Dims: DInsertAt<NewDim, U1, Target, Index>,
+    {
+        let index = <Dims as DInsertAt<NewDim, U1, Target, Index>>::INDEX;
+        NamedTensor::from_tch_tensor(self.tensor.unsqueeze(index as i64))
+    }
+
+    pub fn unsqueeze_end<NewDim>(&self) -> NamedTensor<DExpandEndOutput<Dims, NewDim>, Kind, Dev>
+    where
+        NewDim: Dim,
+        Dims: DAppend<NewDim, U1> + DLength,
+    {
+        let index = DLengthOutput::<Dims>::I64;
+        NamedTensor::from_tch_tensor(self.tensor.unsqueeze(index))
+    }
+
+    pub fn unsqueeze_front<NewDim>(
+        &self,
+    ) -> NamedTensor<DPrependOutput<Dims, NewDim, U1>, Kind, Dev>
+    where
+        NewDim: Dim,
+        Dims: DPrepend<NewDim, U1>,
+    {
+        NamedTensor::from_tch_tensor(self.tensor.unsqueeze(0))
+    }
+
+    pub fn squeeze<Target, Index>(
+        &self,
+    ) -> NamedTensor<DRemoveAtOutput<Dims, Target, Index>, Kind, Dev>
+    where
+        Target: Dim,
+        Index: Counter,
+        Dims: DRemoveAt<Target, Index> + DSizeAt<Target, Index, Output = U1>,
+    {
+        let index = <Dims as DRemoveAt<Target, Index>>::index();
+        NamedTensor::from_tch_tensor(self.tensor.squeeze1(index as i64))
+    }
+
+    /// Removes every dimension of static size one. Dimensions of
+    /// [Dyn](crate::dim::Dyn) size are kept even if the runtime size is one.
+    pub fn squeeze_all(&self) -> NamedTensor<DSqueezeAllOutput<Dims>, Kind, Dev>
+    where
+        Dims: DSqueezeAll,
+    {
+        // squeeze from the highest index so that lower indexes stay valid
+        let tensor = <Dims as DSqueezeAll>::indexes()
+            .into_iter()
+            .rev()
+            .fold(self.tensor.shallow_clone(), |tensor, index| {
+                tensor.squeeze1(index as i64)
+            });
+        NamedTensor::from_tch_tensor(tensor)
+    }
+
+    /// Applies 2D max pooling over the last two dimensions, which must be
+    /// `Height` and `Width`, with a square window. The `Padding` must be
+    /// at most half of `Kernel`.
+    pub fn max_pool2d<Height, Width, Kernel, Stride, Padding>(
+        &self,
+    ) -> NamedTensor<DPool2dOutput<Dims, Height, Width, Kernel, Stride, Padding>, Kind, Dev>
+    where
+        Height: Dim,
+        Width: Dim,
+        Kernel: Unsigned + NonZero + Div<U2>,
+        Stride: Unsigned + NonZero,
+        Padding: Unsigned + IsLessOrEqual<Quot<Kernel, U2>, Output = True>,
+        Dims: DSpatial2d<Height, Width>
+            + DResizeSpatial2d<
+                Height,
+                Width,
+                DConvSizeOutput<DSpatial2dHeight<Dims, Height, Width>, Kernel, Stride, Padding, U1>,
+                DConvSizeOutput<DSpatial2dWidth<Dims, Height, Width>, Kernel, Stride, Padding, U1>,
+            >,
+        DSpatial2dHeight<Dims, Height, Width>: DConvSize<Kernel, Stride, Padding, U1>,
+        DSpatial2dWidth<Dims, Height, Width>: DConvSize<Kernel, Stride, Padding, U1>,
+    {
+        NamedTensor::from_tch_tensor(self.tensor.max_pool2d(
+            &[Kernel::I64, Kernel::I64],
+            &[Stride::I64, Stride::I64],
+            &[Padding::I64, Padding::I64],
+            &[1, 1],
+            false,
+        ))
+    }
+
+    /// Applies 2D average pooling over the last two dimensions, which must be
+    /// `Height` and `Width`, with a square window. The `Padding` must be
+    /// at most half of `Kernel`.
+    pub fn avg_pool2d<Height, Width, Kernel, Stride, Padding>(
+        &self,
+    ) -> NamedTensor<DPool2dOutput<Dims, Height, Width, Kernel, Stride, Padding>, Kind, Dev>
+    where
+        Height: Dim,
+        Width: Dim,
+        Kernel: Unsigned + NonZero + Div<U2>,
+        Stride: Unsigned + NonZero,
+        Padding: Unsigned + IsLessOrEqual<Quot<Kernel, U2>, Output = True>,
+        Dims: DSpatial2d<Height, Width>
+            + DResizeSpatial2d<
+                Height,
+                Width,
+                DConvSizeOutput<DSpatial2dHeight<Dims, Height, Width>, Kernel, Stride, Padding, U1>,
+                DConvSizeOutput<DSpatial2dWidth<Dims, Height, Width>, Kernel, Stride, Padding, U1>,
+            >,
+        DSpatial2dHeight<Dims, Height, Width>: DConvSize<Kernel, Stride, Padding, U1>,
+        DSpatial2dWidth<Dims, Height, Width>: DConvSize<Kernel, Stride, Padding, U1>,
+    {
+        NamedTensor::from_tch_tensor(self.tensor.avg_pool2d(
+            &[Kernel::I64, Kernel::I64],
+            &[Stride::I64, Stride::I64],
+            &[Padding::I64, Padding::I64],
+            false,
+            true,
+            None,
+        ))
+    }
+
+    /// Applies 2D adaptive max pooling, which resizes the last two dimensions
+    /// `Height` and `Width` to `OutHeight` and `OutWidth`.
+    pub fn adaptive_max_pool2d<Height, Width, OutHeight, OutWidth>(
+        &self,
+    ) -> NamedTensor<DResizeSpatial2dOutput<Dims, Height, Width, OutHeight, OutWidth>, Kind, Dev>
+    where
+        Height: Dim,
+        Width: Dim,
+        OutHeight: Unsigned + NonZero,
+        OutWidth: Unsigned + NonZero,
+        Dims: DResizeSpatial2d<Height, Width, OutHeight, OutWidth>,
+    {
+        let (output, _indices) = self
+            .tensor
+            .adaptive_max_pool2d(&[OutHeight::I64, OutWidth::I64]);
+        NamedTensor::from_tch_tensor(output)
+    }
+
+    /// Applies 2D adaptive average pooling, which resizes the last two
+    /// dimensions `Height` and `Width` to `OutHeight` and `OutWidth`.
+    pub fn adaptive_avg_pool2d<Height, Width, OutHeight, OutWidth>(
+        &self,
+    ) -> NamedTensor<DResizeSpatial2dOutput<Dims, Height, Width, OutHeight, OutWidth>, Kind, Dev>
+    where
+        Height: Dim,
+        Width: Dim,
+        OutHeight: Unsigned + NonZero,
+        OutWidth: Unsigned + NonZero,
+        Dims: DResizeSpatial2d<Height, Width, OutHeight, OutWidth>,
+    {
+        NamedTensor::from_tch_tensor(
+            self.tensor
+                .adaptive_avg_pool2d(&[OutHeight::I64, OutWidth::I64]),
+        )
+    }
 }
 
 // tests
 
 #[cfg(test)]
 mod tests {
-    // use super::*;
-    // use crate::{list::LAssertEqualOutput, make_dims, DimListType, TListType};
-    // use typenum::consts::*;
+    use super::*;
+    use crate::{device::Cpu, dim::Dyn, kind::Double, make_dims, DimListType};
+    use type_freak::TListType;
+    use typenum::consts::*;
+
+    make_dims! {A, B, C, D}
+
+    type SomeDims = DimListType! {(A, U3), (B, U2), (C, U4)};
+    type SomeTensor = NamedTensor<SomeDims, Double, Cpu>;
 
     #[test]
     fn named_tensor_test() {
-        // TODO
+        let tensor = SomeTensor::zeros();
+
+        // unsqueeze
+        let _: NamedTensor<DimListType! {(A, U3), (D, U1), (B, U2), (C, U4)}, Double, Cpu> =
+            tensor.unsqueeze_before::<D, B, _>();
+        let _: NamedTensor<DimListType! {(A, U3), (B, U2), (C, U4), (D, U1)}, Double, Cpu> =
+            tensor.unsqueeze_end::<D>();
+        let _: NamedTensor<DimListType! {(D, U1), (A, U3), (B, U2), (C, U4)}, Double, Cpu> =
+            tensor.unsqueeze_front::<D>();
+
+        // squeeze
+        let expanded = tensor.unsqueeze_before::<D, B, _>();
+        let _: NamedTensor<SomeDims, Double, Cpu> = expanded.squeeze::<D, _>();
+        let _: NamedTensor<SomeDims, Double, Cpu> = expanded.squeeze_all();
+
+        // It triggers compile error because the size of B is not one
+        // let _ = tensor.squeeze::<B, _>();
+
+        // align
+        let _: NamedTensor<DimListType! {(A, U1), (B, U2), (D, U1), (C, U4)}, Double, Cpu> = tensor
+            .narrow::<A, U0, U1, _>()
+            .squeeze::<A, _>()
+            .transpose::<TListType! {C, B}, _>()
+            .align_to::<DimListType! {(A, U3), (B, U2), (D, U5), (C, U4)}, _>();
+
+        // rename
+        let _: NamedTensor<DimListType! {(A, U3), (D, U2), (C, U4)}, Double, Cpu> =
+            tensor.rename::<B, D, _>();
+
+        // It triggers compile error because C already exists
+        // let _ = tensor.rename::<B, C, _>();
+
+        // unflatten
+        let _: NamedTensor<DimListType! {(A, U3), (B, U2), (C, U2), (D, U2)}, Double, Cpu> =
+            tensor.unflatten::<C, DimListType! {(C, U2), (D, U2)}, _>();
+
+        // It triggers compile error because 3 * 2 is not 4
+        // let _ = tensor.unflatten::<C, DimListType! {(C, U3), (D, U2)}, _>();
+
+        // reshape and view
+        let _: NamedTensor<DimListType! {(D, U6), (C, U4)}, Double, Cpu> =
+            tensor.reshape::<DimListType! {(D, U6), (C, U4)}>();
+        let _: NamedTensor<DimListType! {(D, U24)}, Double, Cpu> =
+            tensor.view::<DimListType! {(D, U24)}>();
+
+        // It triggers compile error because the numbers of elements differ
+        // let _ = tensor.reshape::<DimListType! {(D, U25)}>();
+
+        // narrow and slice
+        let _: NamedTensor<DimListType! {(A, U3), (B, U2), (C, U3)}, Double, Cpu> =
+            tensor.narrow::<C, U1, U3, _>();
+        let _: NamedTensor<DimListType! {(A, U2), (B, U2), (C, U4)}, Double, Cpu> =
+            tensor.slice::<A, U0, U3, U2, _>();
+
+        // It triggers compile error because the range exceeds the size of C
+        // let _ = tensor.narrow::<C, U2, U3, _>();
+
+        // stack and concatenate many
+        let another = SomeTensor::zeros();
+        let _: NamedTensor<DimListType! {(D, U2), (A, U3), (B, U2), (C, U4)}, Double, Cpu> =
+            SomeTensor::stack::<D, _>([&tensor, &another]);
+        let _: NamedTensor<DimListType! {(A, U3), (D, U3), (B, U2), (C, U4)}, Double, Cpu> =
+            SomeTensor::stack_before::<D, B, _, _>([&tensor, &another, &tensor]);
+        let _: NamedTensor<DimListType! {(A, U3), (B, U2), (C, U4), (D, U1)}, Double, Cpu> =
+            SomeTensor::stack_end::<D, _>([&tensor]);
+        let _: NamedTensor<DimListType! {(A, U3), (B, U6), (C, U4)}, Double, Cpu> =
+            SomeTensor::concat_many::<B, _, _>([&tensor, &another, &tensor]);
+
+        // It triggers compile error because the array is empty
+        // let _ = SomeTensor::stack::<D, _>([]);
+
+        // chunk and split
+        let _: [NamedTensor<DimListType! {(A, U3), (B, U2), (C, U2)}, Double, Cpu>; 2] =
+            tensor.chunk::<C, U2, _>();
+        let _: (
+            NamedTensor<DimListType! {(A, U1), (B, U2), (C, U4)}, Double, Cpu>,
+            NamedTensor<DimListType! {(A, U2), (B, U2), (C, U4)}, Double, Cpu>,
+        ) = tensor.split_at::<A, U1, _>();
+
+        // It triggers compile error because 4 is not divisible by 3
+        // let _ = tensor.chunk::<C, U3, _>();
+    }
+
+    /// A tensor of `SomeDims` filled with `0, 1, 2, ...` in order.
+    fn arange_tensor() -> SomeTensor {
+        let tensor = Tensor::arange(24, (TchKind::Double, TchDevice::Cpu)).view([3, 2, 4]);
+        SomeTensor::try_from_tch(tensor).unwrap()
+    }
+
+    #[test]
+    fn narrow_slice_test() {
+        let tensor = arange_tensor();
+
+        // the range 1..4 of C
+        let output = tensor.narrow::<C, U1, U3, _>();
+        let expect = (0..6)
+            .flat_map(|row| (1..4).map(move |col| (row * 4 + col) as f64))
+            .collect::<Vec<_>>();
+        assert_eq!(output.to_vec(), expect);
+
+        // the indexes 0 and 2 of A, taken with step 2
+        let output = tensor.slice::<A, U0, U3, U2, _>();
+        let expect = (0..8)
+            .chain(16..24)
+            .map(|value| value as f64)
+            .collect::<Vec<_>>();
+        assert_eq!(output.to_vec(), expect);
+
+        // the indexes 1 and 3 of C
+        let output = tensor.slice::<C, U1, U4, U2, _>();
+        let expect = (0..6)
+            .flat_map(|row| vec![(row * 4 + 1) as f64, (row * 4 + 3) as f64])
+            .collect::<Vec<_>>();
+        assert_eq!(output.to_vec(), expect);
+    }
+
+    #[test]
+    fn chunk_split_test() {
+        let tensor = arange_tensor();
+
+        // two halves of C
+        let [first, second] = tensor.chunk::<C, U2, _>();
+        let expect = (0..6)
+            .flat_map(|row| (0..2).map(move |col| (row * 4 + col) as f64))
+            .collect::<Vec<_>>();
+        assert_eq!(first.to_vec(), expect);
+        let expect = (0..6)
+            .flat_map(|row| (2..4).map(move |col| (row * 4 + col) as f64))
+            .collect::<Vec<_>>();
+        assert_eq!(second.to_vec(), expect);
+
+        // the first index of A and the rest
+        let (head, tail) = tensor.split_at::<A, U1, _>();
+        let expect = (0..8).map(|value| value as f64).collect::<Vec<_>>();
+        assert_eq!(head.to_vec(), expect);
+        let expect = (8..24).map(|value| value as f64).collect::<Vec<_>>();
+        assert_eq!(tail.to_vec(), expect);
+    }
+
+    #[test]
+    fn reshape_view_test() {
+        let tensor = arange_tensor();
+        let expect = (0..24).map(|value| value as f64).collect::<Vec<_>>();
+
+        // elements keep the row-major order
+        let output = tensor.reshape::<DimListType! {(D, U6), (C, U4)}>();
+        assert_eq!(output.to_vec(), expect);
+        let output = tensor.view::<DimListType! {(D, U24)}>();
+        assert_eq!(output.to_vec(), expect);
+
+        // reshaping a transposed tensor follows the transposed order
+        let output = tensor
+            .transpose::<TListType! {C, B, A}, _>()
+            .reshape::<DimListType! {(D, U24)}>();
+        let expect = (0..4)
+            .flat_map(|c| (0..2).flat_map(move |b| (0..3).map(move |a| (a * 8 + b * 4 + c) as f64)))
+            .collect::<Vec<_>>();
+        assert_eq!(output.to_vec(), expect);
+    }
+
+    #[test]
+    fn dyn_tensor_test() {
+        type DynDims = DimListType! {(A, Dyn), (B, U2)};
+        type DynTensor = NamedTensor<DynDims, Double, Cpu>;
+
+        // sizes are given and validated at runtime
+        let tensor = DynTensor::zeros_dyn(&[5]).unwrap();
+        assert_eq!(tensor.size(), vec![5, 2]);
+        assert!(DynTensor::zeros_dyn(&[]).is_err());
+        assert_eq!(
+            DynTensor::zeros_dyn(&[-3]).err(),
+            Some(ShapeError::NegativeSize {
+                name: "A",
+                index: 0,
+                actual: -3,
+            })
+        );
+        assert!(DynTensor::randn_dyn(&[-1]).is_err());
+
+        // It triggers compile error because the sizes are not static
+        // let _ = DynTensor::zeros();
+        // let _ = DynTensor::from_slice(&[0.0; 10]);
+
+        let options = (TchKind::Double, TchDevice::Cpu);
+        assert!(DynTensor::try_from_tch(Tensor::zeros(&[7, 2], options)).is_ok());
+        assert!(DynTensor::try_from_tch(Tensor::zeros(&[7, 3], options)).is_err());
+
+        // dynamic sizes are carried through
+        let _: NamedTensor<DimListType! {(C, U1), (A, Dyn), (B, U2)}, Double, Cpu> =
+            tensor.unsqueeze_front::<C>();
+        let _: NamedTensor<DimListType! {(D, Dyn), (B, U2)}, Double, Cpu> =
+            tensor.rename::<A, D, _>();
+        let _: DynTensor = tensor.concat::<A, _, _>(&SomeTensor::zeros().select::<U0, C, _>());
+
+        // concatenation checks the other dynamic sizes at runtime
+        let lhs =
+            NamedTensor::<DimListType! {(A, Dyn), (C, U3)}, Double, Cpu>::zeros_dyn(&[4]).unwrap();
+        let rhs =
+            NamedTensor::<DimListType! {(A, Dyn), (C, U2)}, Double, Cpu>::zeros_dyn(&[4]).unwrap();
+        let output: NamedTensor<DimListType! {(A, Dyn), (C, U5)}, Double, Cpu> =
+            lhs.try_concat::<C, _, _>(&rhs).unwrap();
+        assert_eq!(output.size(), vec![4, 5]);
+        let rhs =
+            NamedTensor::<DimListType! {(A, Dyn), (C, U2)}, Double, Cpu>::zeros_dyn(&[6]).unwrap();
+        assert!(lhs.try_concat::<C, _, _>(&rhs).is_err());
+
+        // dynamic dims of runtime size one are kept by squeeze_all
+        let tensor = DynTensor::zeros_dyn(&[1]).unwrap();
+        let squeezed: DynTensor = tensor.squeeze_all();
+        assert_eq!(squeezed.size(), vec![1, 2]);
+        let tensor = DynTensor::zeros_dyn(&[5]).unwrap();
+
+        // conversion to static sizes is checked at runtime
+        assert!(tensor
+            .try_cast_dims::<DimListType! {(A, U5), (B, U2)}>()
+            .is_ok());
+        assert!(tensor
+            .try_cast_dims::<DimListType! {(A, U4), (B, U2)}>()
+            .is_err());
+    }
+
+    #[test]
+    fn activation_test() {
+        let tensor = SomeTensor::randn();
+
+        // elementwise activations keep the shape
+        let _: SomeTensor = tensor.relu();
+        let _: SomeTensor = tensor.leaky_relu();
+        let _: SomeTensor = tensor.gelu();
+        let _: SomeTensor = tensor.elu();
+        let _: SomeTensor = tensor.silu();
+        let _: SomeTensor = tensor.softplus();
+        assert!(tensor.relu().tensor.min().double_value(&[]) >= 0.0);
+
+        // values of non-trivial activations
+        let values =
+            NamedTensor::<DimListType! {(A, U3)}, Double, Cpu>::from_array(&[-2.0, 0.0, 1.0]);
+        let output = Vec::<f64>::from(&values.leaky_relu_with_slope(0.1).tensor);
+        assert_eq!(output, vec![-0.2, 0.0, 1.0]);
+
+        let output = Vec::<f64>::from(&values.silu().tensor);
+        let expect = [-2.0 / (1.0 + 2f64.exp()), 0.0, 1.0 / (1.0 + (-1f64).exp())];
+        assert!(output
+            .iter()
+            .zip(expect.iter())
+            .all(|(lhs, rhs)| (lhs - rhs).abs() < 1e-9));
+
+        let output = Vec::<f64>::from(&values.softplus().tensor);
+        assert!((output[1] - 2f64.ln()).abs() < 1e-9);
+
+        // softmax along a named dimension
+        let output = tensor.softmax::<B, _>();
+        let sum = output.tensor.sum1(&[1], false, TchKind::Double);
+        assert!((sum - 1.0).abs().max().double_value(&[]) < 1e-6);
+
+        let output = tensor.log_softmax::<C, _>();
+        let sum = output.tensor.exp().sum1(&[2], false, TchKind::Double);
+        assert!((sum - 1.0).abs().max().double_value(&[]) < 1e-6);
+
+        // It triggers compile error because D is not a dimension of the tensor
+        // let _ = tensor.softmax::<D, _>();
+
+        // It triggers compile error because softmax requires a float kind
+        // let _ = NamedTensor::<SomeDims, Int64, Cpu>::zeros().softmax::<B, _>();
+    }
+
+    #[test]
+    fn pool_test() {
+        type ImageTensor =
+            NamedTensor<DimListType! {(A, U2), (B, U3), (C, U8), (D, U6)}, Double, Cpu>;
+        let tensor = ImageTensor::randn();
+
+        // pooling with a window of 2 and stride 2
+        let output: NamedTensor<DimListType! {(A, U2), (B, U3), (C, U4), (D, U3)}, Double, Cpu> =
+            tensor.max_pool2d::<C, D, U2, U2, U0>();
+        assert_eq!(output.size(), vec![2, 3, 4, 3]);
+        let output: NamedTensor<DimListType! {(A, U2), (B, U3), (C, U4), (D, U3)}, Double, Cpu> =
+            tensor.avg_pool2d::<C, D, U2, U2, U0>();
+        assert_eq!(output.size(), vec![2, 3, 4, 3]);
+
+        // pooling with padding
+        let _: NamedTensor<DimListType! {(A, U2), (B, U3), (C, U8), (D, U6)}, Double, Cpu> =
+            tensor.max_pool2d::<C, D, U3, U1, U1>();
+
+        // adaptive pooling
+        let output: NamedTensor<DimListType! {(A, U2), (B, U3), (C, U1), (D, U1)}, Double, Cpu> =
+            tensor.adaptive_avg_pool2d::<C, D, U1, U1>();
+        assert_eq!(output.size(), vec![2, 3, 1, 1]);
+        let _: NamedTensor<DimListType! {(A, U2), (B, U3), (C, U3), (D, U2)}, Double, Cpu> =
+            tensor.adaptive_max_pool2d::<C, D, U3, U2>();
+
+        // It triggers compile error because C and D are not the last two dims
+        // let _ = tensor.max_pool2d::<B, C, U2, U2, U0>();
+
+        // It triggers compile error because the padding exceeds half of the kernel
+        // let _ = tensor.max_pool2d::<C, D, U2, U1, U2>();
     }
 }